@@ -14,27 +14,43 @@ use std::path::Path;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let context = NativeContext::new();
-    let mut aot_cache = AotProgramCache::new(&context);
+    let aot_cache = AotProgramCache::new(&context);
     let mut jit_cache = JitProgramCache::new(&context);
 
     let factorial = load_contract("programs/benches/factorial_2M.cairo");
     let fibonacci = load_contract("programs/benches/fib_2M.cairo");
     let logistic_map = load_contract("programs/benches/logistic_map.cairo");
+    let function_calls = load_contract("programs/function_calls.cairo");
 
-    let aot_factorial = aot_cache.compile_and_insert(Felt::ZERO, &factorial, OptLevel::None);
-    let aot_fibonacci = aot_cache.compile_and_insert(Felt::ONE, &fibonacci, OptLevel::None);
-    let aot_logistic_map =
-        aot_cache.compile_and_insert(Felt::from(2), &logistic_map, OptLevel::None);
+    let aot_factorial = aot_cache
+        .compile_and_insert(Felt::ZERO, &factorial, OptLevel::None)
+        .unwrap();
+    let aot_fibonacci = aot_cache
+        .compile_and_insert(Felt::ONE, &fibonacci, OptLevel::None)
+        .unwrap();
+    let aot_logistic_map = aot_cache
+        .compile_and_insert(Felt::from(2), &logistic_map, OptLevel::None)
+        .unwrap();
 
     let jit_factorial = jit_cache.compile_and_insert(Felt::ZERO, &factorial, OptLevel::None);
     let jit_fibonacci = jit_cache.compile_and_insert(Felt::ONE, &fibonacci, OptLevel::None);
     let jit_logistic_map =
         jit_cache.compile_and_insert(Felt::from(2), &logistic_map, OptLevel::None);
+    let jit_function_calls =
+        jit_cache.compile_and_insert(Felt::from(3), &function_calls, OptLevel::None);
 
     let factorial_function_id = find_function_id(&factorial, "factorial_2M::factorial_2M::main");
     let fibonacci_function_id = find_function_id(&fibonacci, "fib_2M::fib_2M::main");
     let logistic_map_function_id =
         find_function_id(&logistic_map, "logistic_map::logistic_map::main");
+    let without_trap_checks_function_id = find_function_id(
+        &function_calls,
+        "function_calls::function_calls::without_trap_checks",
+    );
+    let with_trap_checks_function_id = find_function_id(
+        &function_calls,
+        "function_calls::function_calls::with_trap_checks",
+    );
 
     c.bench_function("Cached JIT factorial_2M", |b| {
         b.iter(|| jit_factorial.invoke_dynamic(factorial_function_id, &[], Some(u128::MAX)));
@@ -46,6 +62,18 @@ fn criterion_benchmark(c: &mut Criterion) {
         b.iter(|| jit_logistic_map.invoke_dynamic(logistic_map_function_id, &[], Some(u128::MAX)));
     });
 
+    // Both loops make the same number of calls, but only the callee of the second one can trap.
+    c.bench_function("Cached JIT function calls without trap checks", |b| {
+        b.iter(|| {
+            jit_function_calls.invoke_dynamic(without_trap_checks_function_id, &[], Some(u128::MAX))
+        });
+    });
+    c.bench_function("Cached JIT function calls with trap checks", |b| {
+        b.iter(|| {
+            jit_function_calls.invoke_dynamic(with_trap_checks_function_id, &[], Some(u128::MAX))
+        });
+    });
+
     c.bench_function("Cached AOT factorial_2M", |b| {
         b.iter(|| aot_factorial.invoke_dynamic(factorial_function_id, &[], Some(u128::MAX)));
     });
@@ -0,0 +1,37 @@
+// Calls the same kind of function in a loop, once with a callee which can't trap and once with one
+// which can. Callers only check whether their callee trapped in the second case.
+
+#[inline(never)]
+fn add(a: felt252, b: felt252) -> felt252 {
+    a + b
+}
+
+#[inline(never)]
+fn add_some(a: Option<felt252>, b: felt252) -> felt252 {
+    match a {
+        Option::Some(a) => a + b,
+        Option::None => b,
+    }
+}
+
+fn sum(acc: felt252, n: felt252) -> felt252 {
+    match n {
+        0 => acc,
+        _ => sum(add(acc, n), n - 1),
+    }
+}
+
+fn sum_some(acc: felt252, n: felt252) -> felt252 {
+    match n {
+        0 => acc,
+        _ => sum_some(add_some(Option::Some(acc), n), n - 1),
+    }
+}
+
+fn without_trap_checks() -> felt252 {
+    sum(0, 100000)
+}
+
+fn with_trap_checks() -> felt252 {
+    sum_some(0, 100000)
+}
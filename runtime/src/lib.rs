@@ -7,7 +7,10 @@ use lazy_static::lazy_static;
 use starknet_crypto::FieldElement;
 use starknet_curve::AffinePoint;
 use starknet_types_core::felt::Felt;
-use std::{collections::HashMap, fs::File, io::Write, os::fd::FromRawFd, ptr::NonNull, slice};
+use std::{
+    cell::Cell, collections::HashMap, ffi::c_void, fs::File, io::Write, os::fd::FromRawFd,
+    ptr::NonNull, slice,
+};
 
 lazy_static! {
     pub static ref HALF_PRIME: FieldElement = FieldElement::from_dec_str(
@@ -18,6 +21,92 @@ lazy_static! {
         (DICT_SQUASH_UNIQUE_KEY_COST.cost() - DICT_SQUASH_REPEATED_ACCESS_COST.cost()) as u64;
 }
 
+/// Receives every statement trace event, along with the data pointer it was installed with.
+///
+/// See [`cairo_native__trace`].
+pub type TraceHook = unsafe extern "C" fn(
+    data: *mut c_void,
+    statement_idx: u64,
+    var_id: u64,
+    type_id: u64,
+    value_ptr: *const u8,
+    value_size: u64,
+);
+
+thread_local! {
+    static TRAP_CODE: Cell<u64> = const { Cell::new(0) };
+    static TRACE_HOOK: Cell<Option<(TraceHook, *mut c_void)>> = const { Cell::new(None) };
+}
+
+/// Record that the program reached an invalid state, instead of aborting the process.
+///
+/// Only the first trap is kept until it's retrieved using [`cairo_native__take_trap`].
+///
+/// # Safety
+///
+/// This function is intended to be called from MLIR.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_native__trap(code: u64) {
+    TRAP_CODE.with(|trap_code| {
+        if trap_code.get() == 0 {
+            trap_code.set(code);
+        }
+    });
+}
+
+/// Return the code of the pending trap without clearing it, or zero if there isn't any.
+///
+/// # Safety
+///
+/// This function is intended to be called from MLIR after every function call, so that callers
+/// return as soon as their callee has trapped.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_native__peek_trap() -> u64 {
+    TRAP_CODE.with(|trap_code| trap_code.get())
+}
+
+/// Return and clear the code of the pending trap, or zero if there isn't any.
+///
+/// # Safety
+///
+/// This function is intended to be called by the executor after every invocation.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_native__take_trap() -> u64 {
+    TRAP_CODE.with(|trap_code| trap_code.replace(0))
+}
+
+/// Report that the variable `var_id`, of type `type_id`, was produced by the statement
+/// `statement_idx`. Its value is stored in the `value_size` bytes at `value_ptr`.
+///
+/// The event is forwarded to the hook installed using [`cairo_native__set_trace_hook`], if any.
+///
+/// # Safety
+///
+/// This function is intended to be called from MLIR when compiling with statement tracing.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_native__trace(
+    statement_idx: u64,
+    var_id: u64,
+    type_id: u64,
+    value_ptr: *const u8,
+    value_size: u64,
+) {
+    if let Some((hook, data)) = TRACE_HOOK.get() {
+        hook(data, statement_idx, var_id, type_id, value_ptr, value_size);
+    }
+}
+
+/// Install the hook which receives the statement trace events of the current thread, or remove it
+/// if `hook` is null.
+///
+/// # Safety
+///
+/// The hook must be able to handle events with `data` until it's removed.
+#[no_mangle]
+pub unsafe extern "C" fn cairo_native__set_trace_hook(hook: Option<TraceHook>, data: *mut c_void) {
+    TRACE_HOOK.set(hook.map(|hook| (hook, data)));
+}
+
 /// Based on `cairo-lang-runner`'s implementation.
 ///
 /// Source: <https://github.com/starkware-libs/cairo/blob/main/crates/cairo-lang-runner/src/casm_run/mod.rs#L1946-L1948>
@@ -480,3 +569,80 @@ pub unsafe extern "C" fn cairo_native__libfunc__ec__ec_state_try_finalize_nz(
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, os::fd::AsRawFd};
+
+    #[test]
+    fn debug_print_short_strings() {
+        let path = std::env::temp_dir().join(format!("cairo_native_print_{}", std::process::id()));
+        let target = File::create(&path).unwrap();
+
+        let data = [
+            Felt::from_bytes_be_slice(b"hello").to_bytes_le(),
+            Felt::from(1000).to_bytes_le(),
+        ];
+        let status = unsafe {
+            cairo_native__libfunc__debug__print(
+                target.as_raw_fd(),
+                data.as_ptr(),
+                data.len() as u32,
+            )
+        };
+        drop(target);
+
+        let output = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(status, 0);
+        assert_eq!(output, "[DEBUG]\t68656c6c6f ('hello')\n[DEBUG]\t3e8\n");
+    }
+
+    #[test]
+    fn trap_keeps_first_code() {
+        unsafe {
+            assert_eq!(cairo_native__take_trap(), 0);
+
+            cairo_native__trap(1);
+            cairo_native__trap(2);
+            assert_eq!(cairo_native__take_trap(), 1);
+            assert_eq!(cairo_native__take_trap(), 0);
+        }
+    }
+
+    #[test]
+    fn trace_forwards_to_hook() {
+        unsafe extern "C" fn hook(
+            data: *mut c_void,
+            statement_idx: u64,
+            var_id: u64,
+            type_id: u64,
+            value_ptr: *const u8,
+            value_size: u64,
+        ) {
+            let events = &mut *data.cast::<Vec<(u64, u64, u64, Vec<u8>)>>();
+            events.push((
+                statement_idx,
+                var_id,
+                type_id,
+                slice::from_raw_parts(value_ptr, value_size as usize).to_vec(),
+            ));
+        }
+
+        let mut events = Vec::new();
+        unsafe {
+            // Events without a hook are discarded.
+            cairo_native__trace(0, 0, 0, [1u8].as_ptr(), 1);
+
+            cairo_native__set_trace_hook(Some(hook), (&mut events as *mut Vec<_>).cast());
+            cairo_native__trace(1, 2, 3, 42u32.to_le_bytes().as_ptr(), 4);
+            cairo_native__set_trace_hook(None, std::ptr::null_mut());
+
+            cairo_native__trace(4, 5, 6, [1u8].as_ptr(), 1);
+        }
+
+        assert_eq!(events, [(1, 2, 3, 42u32.to_le_bytes().to_vec())]);
+    }
+}
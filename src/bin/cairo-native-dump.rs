@@ -4,7 +4,12 @@ use cairo_lang_compiler::{
 };
 use cairo_lang_defs::plugin::NamedPlugin;
 use cairo_lang_semantic::plugin::PluginSuite;
-use cairo_lang_sierra::{program::Program, ProgramParser};
+use cairo_lang_sierra::{
+    extensions::core::{CoreLibfunc, CoreType},
+    program::Program,
+    program_registry::ProgramRegistry,
+    ProgramParser,
+};
 use cairo_lang_starknet::{
     compile::compile_contract_in_prepared_db, inline_macros::selector::SelectorMacro,
     plugin::StarkNetPlugin,
@@ -12,8 +17,10 @@ use cairo_lang_starknet::{
 use cairo_native::{
     context::NativeContext,
     debug_info::{DebugInfo, DebugLocations},
+    module_to_assembly, module_to_llvm_ir,
+    statistics::CompilationStats,
 };
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use melior::{ir::operation::OperationPrintingFlags, Context};
 use std::{
     ffi::OsStr,
@@ -44,13 +51,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )?;
 
     // Compile the program.
-    let module = context.compile(&program, debug_info)?;
+    let module = match context.compile(&program, debug_info) {
+        Ok(module) => module,
+        Err(e) => {
+            // The statistics which only depend on the program are still useful to find out why it
+            // couldn't be compiled.
+            if args.stats {
+                let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program)?;
+                print_stats(&CompilationStats::from_program(&program, &registry)?);
+            }
+            return Err(e.into());
+        }
+    };
+
+    if args.stats {
+        print_stats(
+            module
+                .get_metadata::<CompilationStats>()
+                .expect("the compiler always collects statistics"),
+        );
+    }
 
     // Write the output.
-    let output_str = module
-        .module()
-        .as_operation()
-        .to_string_with_flags(OperationPrintingFlags::new().enable_debug_info(true, false))?;
+    let output_str = match args.emit {
+        EmitKind::Mlir => module
+            .module()
+            .as_operation()
+            .to_string_with_flags(OperationPrintingFlags::new().enable_debug_info(true, false))?,
+        EmitKind::Llvm => module_to_llvm_ir(module.module())?,
+        EmitKind::Asm => module_to_assembly(module.module(), args.opt_level.into())?,
+    };
     match args.output {
         CompilerOutput::Stdout => println!("{output_str}"),
         CompilerOutput::Path(path) => fs::write(path, &output_str)?,
@@ -59,6 +89,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn print_stats(stats: &CompilationStats) {
+    eprintln!("{:<40} {:>8}", "libfunc", "count");
+    for (name, count) in &stats.libfunc_counts {
+        eprintln!("{name:<40} {count:>8}");
+    }
+    eprintln!();
+    eprintln!("unsupported libfuncs: {:?}", stats.unsupported_libfuncs);
+    eprintln!("functions:            {}", stats.num_functions);
+    eprintln!("MLIR operations:      {}", stats.num_operations);
+    eprintln!("compilation time:     {:?}", stats.compilation_time);
+    eprintln!("  types:              {:?}", stats.types_time);
+    eprintln!("  libfuncs:           {:?}", stats.libfuncs_time);
+    eprintln!("  statements:         {:?}", stats.statements_time);
+    eprintln!("passes time:          {:?}", stats.passes_time);
+}
+
 fn load_program<'c>(
     path: &Path,
     context: Option<&'c Context>,
@@ -155,6 +201,29 @@ struct CmdLine {
     /// Compile a starknet contract
     #[clap(long)]
     starknet: bool,
+
+    /// Print compilation statistics to stderr.
+    #[clap(long)]
+    stats: bool,
+
+    /// The kind of output to emit.
+    #[clap(long, value_enum, default_value_t = EmitKind::Mlir)]
+    emit: EmitKind,
+
+    /// Optimization level used when emitting assembly. Valid: 0, 1, 2, 3. Values higher than 3
+    /// are considered as 3.
+    #[clap(short = 'O', long, default_value_t = 0)]
+    opt_level: u8,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum EmitKind {
+    /// The MLIR module, already lowered into the LLVM dialect.
+    Mlir,
+    /// The LLVM IR translated from the MLIR module.
+    Llvm,
+    /// The host target's assembly code.
+    Asm,
 }
 
 #[derive(Clone, Debug)]
@@ -180,10 +249,10 @@ fn parse_output(input: &str) -> Result<CompilerOutput, String> {
         CompilerOutput::Stdout
     } else {
         CompilerOutput::Path(match Path::new(input).extension().and_then(OsStr::to_str) {
-            Some("mlir") => input.into(),
+            Some("mlir" | "ll" | "s") => input.into(),
             _ => {
                 return Err(
-                    "Output path expected to be `-` for stdout or have `mlir` as its extension."
+                    "Output path expected to be `-` for stdout or have `mlir`, `ll` or `s` as its extension."
                         .to_string(),
                 )
             }
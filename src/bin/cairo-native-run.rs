@@ -1,6 +1,7 @@
 mod utils;
 
 use anyhow::Context;
+use cairo_felt::Felt252;
 use cairo_lang_compiler::{
     db::RootDatabase,
     diagnostics::DiagnosticsReporter,
@@ -15,12 +16,19 @@ use cairo_lang_sierra_generator::{
 use cairo_lang_starknet::contract::get_contracts_info;
 use cairo_native::{
     context::NativeContext,
+    contract_class::NativeContractClass,
     debug_info::{DebugInfo, DebugLocations},
     executor::{AotNativeExecutor, JitNativeExecutor, NativeExecutor},
     metadata::gas::{GasMetadata, MetadataComputationConfig},
+    starknet_stub::StubSyscallHandler,
+    values::JitValue,
 };
 use clap::{Parser, ValueEnum};
-use std::path::{Path, PathBuf};
+use starknet_types_core::felt::Felt;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 use tracing_subscriber::{EnvFilter, FmtSubscriber};
 use utils::{find_function, result_to_runresult};
 
@@ -36,7 +44,8 @@ enum RunMode {
 #[clap(version, verbatim_doc_comment)]
 struct Args {
     /// The Cairo project path to compile and run its tests.
-    path: PathBuf,
+    #[arg(required_unless_present = "contract_class")]
+    path: Option<PathBuf>,
     /// Whether path is a single file.
     #[arg(short, long)]
     single_file: bool,
@@ -52,6 +61,32 @@ struct Args {
     /// Optimization level, Valid: 0, 1, 2, 3. Values higher than 3 are considered as 3.
     #[arg(short = 'O', long, default_value_t = 0)]
     opt_level: u8,
+    /// The name of the function to run.
+    #[arg(long, default_value = "main")]
+    entry: String,
+    /// A JSON file containing the array of arguments to pass to the entry point.
+    #[arg(long)]
+    args_file: Option<PathBuf>,
+    /// Write the execution result as JSON into this file (or `-` for stdout).
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// A Sierra contract class JSON file to run instead of a Cairo project.
+    #[arg(long)]
+    contract_class: Option<PathBuf>,
+    /// The selector of the contract class entry point to run.
+    #[arg(long, requires = "contract_class", value_parser = parse_felt)]
+    selector: Option<Felt>,
+    /// Comma-separated calldata to pass to the contract class entry point.
+    #[arg(long, requires = "contract_class", value_parser = parse_felt, value_delimiter = ',')]
+    calldata: Vec<Felt>,
+}
+
+fn parse_felt(value: &str) -> Result<Felt, String> {
+    match value.strip_prefix("0x") {
+        Some(_) => Felt::from_hex(value),
+        None => Felt::from_dec_str(value),
+    }
+    .map_err(|_| format!("invalid felt: {value}"))
 }
 
 fn main() -> anyhow::Result<()> {
@@ -64,19 +99,27 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
-    // Check if args.path is a file or a directory.
-    check_compiler_path(args.single_file, &args.path)?;
+    if let Some(contract_class) = &args.contract_class {
+        return run_contract_class(&args, contract_class);
+    }
+    let path = args
+        .path
+        .as_deref()
+        .with_context(|| "Missing the Cairo project path.")?;
+
+    // Check if path is a file or a directory.
+    check_compiler_path(args.single_file, path)?;
 
     let db = &mut RootDatabase::builder().detect_corelib().build()?;
 
-    let main_crate_ids = setup_project(db, Path::new(&args.path))?;
+    let main_crate_ids = setup_project(db, path)?;
 
     let mut reporter = DiagnosticsReporter::stderr();
     if args.allow_warnings {
         reporter = reporter.allow_warnings();
     }
     if reporter.check(db) {
-        anyhow::bail!("failed to compile: {}", args.path.display());
+        anyhow::bail!("failed to compile: {}", path.display());
     }
 
     let sierra_program = db
@@ -93,7 +136,7 @@ fn main() -> anyhow::Result<()> {
     let _contracts_info = get_contracts_info(db, main_crate_ids, &replacer)?;
     let sierra_program = replacer.apply(&sierra_program);
 
-    let native_context = NativeContext::new();
+    let native_context = NativeContext::new().with_opt_level(args.opt_level.into());
 
     let debug_locations = {
         let debug_info = DebugInfo::extract(db, &sierra_program)
@@ -124,16 +167,26 @@ fn main() -> anyhow::Result<()> {
     let gas_metadata =
         GasMetadata::new(&sierra_program, Some(MetadataComputationConfig::default())).unwrap();
 
-    let func = find_function(&sierra_program, "::main")?;
+    let func = find_function(&sierra_program, &format!("::{}", args.entry))?;
 
     let initial_gas = gas_metadata
         .get_initial_available_gas(&func.id, args.available_gas.map(|x| x.try_into().unwrap()))
         .with_context(|| "not enough gas to run")?;
 
+    let func_args: Vec<JitValue> = match &args.args_file {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)
+            .with_context(|| format!("Invalid arguments file: {}", path.display()))?,
+        None => Vec::new(),
+    };
+
     let result = native_executor
-        .invoke_dynamic(&func.id, &[], Some(initial_gas))
+        .invoke_dynamic(&func.id, &func_args, Some(initial_gas))
         .with_context(|| "Failed to run the function.")?;
 
+    if let Some(output) = &args.output {
+        return write_output(output, &result);
+    }
+
     let run_result = result_to_runresult(&result)?;
 
     match run_result {
@@ -153,6 +206,90 @@ fn main() -> anyhow::Result<()> {
     }
     if let Some(gas) = result.remaining_gas {
         println!("Remaining gas: {gas}");
+        println!("Consumed gas: {}", initial_gas - gas);
+    }
+
+    Ok(())
+}
+
+/// Run the contract class entry point with the selector given in the arguments.
+fn run_contract_class(args: &Args, path: &Path) -> anyhow::Result<()> {
+    let contract_class = NativeContractClass::from_json(&fs::read_to_string(path)?)
+        .with_context(|| format!("Invalid contract class: {}", path.display()))?;
+
+    let selector = args
+        .selector
+        .with_context(|| "Running a contract class requires a `--selector`.")?;
+    let entry_point = contract_class
+        .find_entry_point(&selector)
+        .with_context(|| format!("No entry point found with selector {selector}."))?;
+
+    let native_context = NativeContext::new().with_opt_level(args.opt_level.into());
+    let native_module = native_context
+        .compile(contract_class.program(), None)
+        .with_context(|| "Failed to compile the contract class.")?;
+
+    let native_executor: NativeExecutor = match args.run_mode {
+        RunMode::Aot => {
+            AotNativeExecutor::from_native_module(native_module, args.opt_level.into()).into()
+        }
+        RunMode::Jit => {
+            JitNativeExecutor::from_native_module(native_module, args.opt_level.into()).into()
+        }
+    };
+
+    // The executor subtracts the entry point's initial cost by itself, this is only used to report
+    // the consumed gas.
+    let available_gas = args.available_gas.map(|x| x.try_into().unwrap());
+    let initial_gas = GasMetadata::new(
+        contract_class.program(),
+        Some(MetadataComputationConfig::default()),
+    )
+    .unwrap()
+    .get_initial_available_gas(&entry_point.function_id, available_gas)
+    .with_context(|| "not enough gas to run")?;
+
+    let result = native_executor
+        .invoke_contract_dynamic(
+            &entry_point.function_id,
+            &args.calldata,
+            available_gas,
+            &mut StubSyscallHandler::default(),
+        )
+        .with_context(|| "Failed to run the entry point.")?;
+
+    if let Some(output) = &args.output {
+        return write_output(output, &result);
+    }
+
+    if result.failure_flag {
+        print!("Run panicked with [");
+        for value in &result.return_values {
+            match as_cairo_short_string(&Felt252::from_bytes_be(&value.to_bytes_be())) {
+                Some(as_string) => print!("{value} ('{as_string}'), "),
+                None => print!("{value}, "),
+            }
+        }
+        println!("].")
+    } else {
+        println!(
+            "Run completed successfully, returning {:?}",
+            result.return_values
+        );
+    }
+    println!("Remaining gas: {}", result.remaining_gas);
+    println!("Consumed gas: {}", initial_gas - result.remaining_gas);
+
+    Ok(())
+}
+
+/// Write an execution result as JSON to the `--output` path, or to stdout if it's `-`.
+fn write_output(output: &Path, result: &impl serde::Serialize) -> anyhow::Result<()> {
+    let result_json = serde_json::to_string_pretty(result)?;
+    if output == Path::new("-") {
+        println!("{result_json}");
+    } else {
+        fs::write(output, result_json)?;
     }
 
     Ok(())
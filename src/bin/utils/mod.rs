@@ -225,6 +225,7 @@ mod tests {
                     debug_name: Some("debug_name".into()),
                 },
                 builtin_stats: Default::default(),
+                is_panic_result: false,
             })
             .unwrap(),
             RunResultValue::Success(vec![
@@ -248,6 +249,7 @@ mod tests {
                     debug_name: Some("core::panics::PanicResult::Test".into()),
                 },
                 builtin_stats: Default::default(),
+                is_panic_result: true,
             })
             .unwrap(),
             RunResultValue::Success(vec![Felt252::from(24)])
@@ -266,6 +268,7 @@ mod tests {
                 debug_name: Some("core::panics::PanicResult::Test".into()),
             },
             builtin_stats: Default::default(),
+            is_panic_result: true,
         })
         .unwrap();
     }
@@ -282,6 +285,7 @@ mod tests {
                 debug_name: None,
             },
             builtin_stats: Default::default(),
+            is_panic_result: false,
         })
         .unwrap();
     }
@@ -306,6 +310,7 @@ mod tests {
                     debug_name: Some("core::panics::PanicResult::Test".into()),
                 },
                 builtin_stats: Default::default(),
+                is_panic_result: true,
             })
             .unwrap(),
             RunResultValue::Panic(vec![
@@ -324,6 +329,7 @@ mod tests {
                 remaining_gas: None,
                 return_value: JitValue::Uint8(10),
                 builtin_stats: Default::default(),
+                is_panic_result: false,
             })
             .unwrap(),
             RunResultValue::Success(vec![Felt252::from(10)])
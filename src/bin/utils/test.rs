@@ -143,7 +143,7 @@ pub fn run_tests(
                 linear_gas_solver: true,
             },
         )
-        .unwrap();
+        .context("Failed to compile the test program.")?;
 
     let native_executor: NativeExecutor = match args.run_mode {
         RunMode::Aot => {
@@ -265,3 +265,139 @@ pub fn run_tests(
         });
     wrapped_summary.into_inner().unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cairo_lang_compiler::{db::RootDatabase, project::setup_project};
+    use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+    use cairo_lang_test_plugin::{compile_test_prepared_db, test_plugin_suite};
+    use std::io::Write;
+
+    fn compile_tests(source: &str) -> TestCompilation {
+        let mut file = tempfile::Builder::new()
+            .prefix("test_")
+            .suffix(".cairo")
+            .tempfile()
+            .unwrap();
+        file.write_all(source.as_bytes()).unwrap();
+
+        let db = &mut {
+            let mut b = RootDatabase::builder();
+            b.detect_corelib();
+            b.with_cfg(CfgSet::from_iter([Cfg::name("test")]));
+            b.with_plugin_suite(test_plugin_suite());
+            b.build().unwrap()
+        };
+
+        let main_crate_ids = setup_project(db, file.path()).unwrap();
+        compile_test_prepared_db(db, false, main_crate_ids.clone(), main_crate_ids).unwrap()
+    }
+
+    #[test]
+    fn run_tests_summary() {
+        let compiled = compile_tests(
+            r#"
+                #[test]
+                fn passes() {
+                    assert(1 + 1 == 2, 'math is broken');
+                }
+
+                #[test]
+                fn fails() {
+                    assert(1 + 1 == 3, 'boom');
+                }
+
+                #[test]
+                #[should_panic(expected: ('boom',))]
+                fn panics_as_expected() {
+                    assert(false, 'boom');
+                }
+
+                #[test]
+                #[should_panic(expected: ('boom',))]
+                fn panics_with_other_data() {
+                    assert(false, 'bang');
+                }
+
+                #[test]
+                #[should_panic]
+                fn does_not_panic() {}
+
+                #[test]
+                #[ignore]
+                fn ignored() {}
+            "#,
+        );
+
+        let (compiled, filtered_out) = filter_test_cases(compiled, false, false, String::new());
+        assert_eq!(filtered_out, 0);
+
+        let summary = run_tests(
+            compiled.named_tests,
+            compiled.sierra_program,
+            compiled.function_set_costs,
+            RunArgs {
+                run_mode: RunMode::Jit,
+                opt_level: 0,
+            },
+        )
+        .unwrap();
+
+        let names = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| name.rsplit("::").next().unwrap().to_string())
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(names(&summary.passed), ["panics_as_expected", "passes"]);
+        assert_eq!(
+            names(&summary.failed),
+            ["does_not_panic", "fails", "panics_with_other_data"]
+        );
+        assert_eq!(names(&summary.ignored), ["ignored"]);
+
+        let panics = summary
+            .failed_run_results
+            .into_iter()
+            .filter_map(|result| match result {
+                RunResultValue::Panic(values) => Some(format_for_panic(values.into_iter())),
+                RunResultValue::Success(_) => None,
+            })
+            .sorted()
+            .collect_vec();
+        assert_eq!(
+            panics,
+            [
+                "Panicked with 0x62616e67 ('bang').",
+                "Panicked with 0x626f6f6d ('boom').",
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_test_cases_by_name() {
+        let source = r#"
+            #[test]
+            fn first() {}
+
+            #[test]
+            fn second() {}
+
+            #[test]
+            #[ignore]
+            fn third() {}
+        "#;
+
+        let (filtered, filtered_out) =
+            filter_test_cases(compile_tests(source), false, false, "second".to_string());
+        assert_eq!(filtered_out, 2);
+        assert_eq!(filtered.named_tests.len(), 1);
+
+        let (filtered, filtered_out) =
+            filter_test_cases(compile_tests(source), false, true, String::new());
+        assert_eq!(filtered_out, 2);
+        assert!(filtered.named_tests.iter().all(|(_, test)| !test.ignored));
+    }
+}
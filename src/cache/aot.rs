@@ -1,22 +1,52 @@
 use crate::{
-    context::NativeContext, executor::AotNativeExecutor, metadata::gas::GasMetadata,
-    module::NativeModule, utils::SHARED_LIBRARY_EXT, OptLevel,
+    context::NativeContext,
+    error::{Error, Result},
+    executor::AotNativeExecutor,
+    metadata::gas::GasMetadata,
+    module::NativeModule,
+    utils::SHARED_LIBRARY_EXT,
+    OptLevel,
+};
+use cairo_lang_sierra::{
+    extensions::core::{CoreLibfunc, CoreType},
+    program::Program,
+    program_registry::ProgramRegistry,
 };
-use cairo_lang_sierra::program::Program;
 use libloading::Library;
+use sha2::{Digest, Sha256};
 use std::{
     collections::HashMap,
     fmt::{self, Debug},
+    fs,
     hash::Hash,
-    rc::Rc,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
 };
 
+/// Version of the on-disk libraries. It's part of their hash, so that libraries built by other
+/// versions are never loaded.
+const CACHE_VERSION: &str = concat!("cairo-native-", env!("CARGO_PKG_VERSION"));
+
+/// Holds the executor of a key once it's compiled. Callers hold its lock while compiling so that
+/// concurrent requests for the same key wait for the first one instead of compiling again. If the
+/// compilation panics the lock is poisoned with the entry still empty, so the poison is ignored and
+/// the next caller compiles it again.
+type CacheEntry = Arc<Mutex<Option<Arc<AotNativeExecutor>>>>;
+
+/// A cache for programs compiled into shared libraries with the same context.
+///
+/// The cache can be shared between threads.
 pub struct AotProgramCache<'a, K>
 where
     K: PartialEq + Eq + Hash,
 {
     context: &'a NativeContext,
-    cache: HashMap<K, Rc<AotNativeExecutor>>,
+    cache: Mutex<HashMap<K, CacheEntry>>,
+    cache_dir: Option<PathBuf>,
+    compilations: AtomicUsize,
 }
 
 impl<'a, K> AotProgramCache<'a, K>
@@ -27,48 +57,146 @@ where
         Self {
             context,
             cache: Default::default(),
+            cache_dir: None,
+            compilations: AtomicUsize::new(0),
         }
     }
 
-    pub fn get(&self, key: &K) -> Option<Rc<AotNativeExecutor>> {
-        self.cache.get(key).cloned()
+    /// Also store the compiled shared libraries in a directory, so that they can be reused by other
+    /// caches (even from other processes).
+    ///
+    /// Libraries are looked up by a SHA-256 hash of the program, every compilation option and the
+    /// compiler's version.
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
     }
 
+    /// Returns the number of programs this cache has compiled. Libraries found in the cache
+    /// directory aren't counted.
+    pub fn compilations(&self) -> usize {
+        self.compilations.load(Ordering::Relaxed)
+    }
+
+    /// Returns the executor of a key, waiting for it if the key is being compiled.
+    pub fn get(&self, key: &K) -> Option<Arc<AotNativeExecutor>> {
+        let entry = self.cache.lock().unwrap().get(key)?.clone();
+        let executor = entry.lock().unwrap_or_else(PoisonError::into_inner);
+        executor.clone()
+    }
+
+    /// Returns the executor of a key, compiling the program if no other call has done it yet.
     pub fn compile_and_insert(
-        &mut self,
+        &self,
         key: K,
         program: &Program,
         opt_level: OptLevel,
-    ) -> Rc<AotNativeExecutor> {
+    ) -> Result<Arc<AotNativeExecutor>> {
+        let entry = self.cache.lock().unwrap().entry(key).or_default().clone();
+
+        let mut entry = entry.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(executor) = entry.as_ref() {
+            return Ok(executor.clone());
+        }
+
+        let executor = Arc::new(match &self.cache_dir {
+            Some(cache_dir) => self.load_or_compile(cache_dir, program, opt_level)?,
+            None => {
+                let shared_library_path = tempfile::Builder::new()
+                    .prefix("lib")
+                    .suffix(&format!(".{SHARED_LIBRARY_EXT}"))
+                    .tempfile()?
+                    .into_temp_path();
+                let (registry, gas_metadata) =
+                    self.compile(program, opt_level, &shared_library_path)?;
+
+                AotNativeExecutor::new(
+                    unsafe { Library::new(&shared_library_path)? },
+                    registry,
+                    gas_metadata,
+                )
+            }
+        });
+        *entry = Some(executor.clone());
+
+        Ok(executor)
+    }
+
+    fn load_or_compile(
+        &self,
+        cache_dir: &Path,
+        program: &Program,
+        opt_level: OptLevel,
+    ) -> Result<AotNativeExecutor> {
+        let name = format!(
+            "lib{}",
+            self.program_hash(CACHE_VERSION, program, opt_level)
+        );
+        let shared_library_path = cache_dir.join(&name).with_extension(SHARED_LIBRARY_EXT);
+
+        let (registry, gas_metadata) = if shared_library_path.exists() {
+            // The library is already compiled, only the registry and gas metadata are needed.
+            (
+                ProgramRegistry::new(program)?,
+                GasMetadata::for_program(program)?,
+            )
+        } else {
+            fs::create_dir_all(cache_dir)?;
+
+            // The library is written elsewhere in the cache directory and then renamed into place,
+            // so that other processes never load a partially written library.
+            let temp_library_path = tempfile::Builder::new()
+                .prefix(&name)
+                .suffix(".tmp")
+                .tempfile_in(cache_dir)?
+                .into_temp_path();
+            let compiled = self.compile(program, opt_level, &temp_library_path)?;
+            temp_library_path
+                .persist(&shared_library_path)
+                .map_err(|e| e.error)?;
+
+            compiled
+        };
+
+        Ok(AotNativeExecutor::new(
+            unsafe { Library::new(&shared_library_path)? },
+            registry,
+            gas_metadata,
+        ))
+    }
+
+    fn program_hash(&self, version: &str, program: &Program, opt_level: OptLevel) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(version);
+        hasher.update("\n");
+        hasher.update(self.context.options_key());
+        hasher.update(format!("\nllvm_opt_level={opt_level:?}\n"));
+        hasher.update(program.to_string());
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn compile(
+        &self,
+        program: &Program,
+        opt_level: OptLevel,
+        shared_library_path: &Path,
+    ) -> Result<(ProgramRegistry<CoreType, CoreLibfunc>, GasMetadata)> {
         let NativeModule {
             module,
             registry,
-            metadata,
-        } = self.context.compile(program, None).expect("should compile");
+            mut metadata,
+        } = self.context.compile(program, None)?;
+        self.compilations.fetch_add(1, Ordering::Relaxed);
 
         // Compile module into an object.
-        let object_data = crate::ffi::module_to_object(&module, opt_level).unwrap();
+        let object_data = crate::ffi::module_to_object(&module, opt_level)
+            .map_err(|e| Error::LLVMCompileError(e.to_string()))?;
 
         // Compile object into a shared library.
-        let shared_library_path = tempfile::Builder::new()
-            .prefix("lib")
-            .suffix(SHARED_LIBRARY_EXT)
-            .tempfile()
-            .unwrap()
-            .into_temp_path();
-        crate::ffi::object_to_shared_lib(&object_data, &shared_library_path).unwrap();
-
-        let shared_library = unsafe { Library::new(shared_library_path).unwrap() };
-        let executor = AotNativeExecutor::new(
-            shared_library,
-            registry,
-            metadata.get::<GasMetadata>().cloned().unwrap(),
-        );
+        crate::ffi::object_to_shared_lib(&object_data, shared_library_path)?;
 
-        let executor = Rc::new(executor);
-        self.cache.insert(key, executor.clone());
-
-        executor
+        let gas_metadata = metadata.remove().ok_or(Error::MissingMetadata)?;
+        Ok((registry, gas_metadata))
     }
 }
 
@@ -86,11 +214,12 @@ mod tests {
     use super::*;
     use crate::{utils::test::load_cairo, values::JitValue};
     use starknet_types_core::felt::Felt;
+    use std::thread;
 
     #[test]
     fn test_aot_compile_and_insert() {
         let native_context = NativeContext::new();
-        let mut cache = AotProgramCache::new(&native_context);
+        let cache = AotProgramCache::new(&native_context);
 
         let (_, program) = load_cairo! {
             fn run_test() -> felt252 {
@@ -99,12 +228,151 @@ mod tests {
         };
 
         let function_id = &program.funcs.first().expect("should have a function").id;
-        let executor = cache.compile_and_insert((), &program, OptLevel::default());
+        let executor = cache
+            .compile_and_insert((), &program, OptLevel::default())
+            .unwrap();
         let res = executor
             .invoke_dynamic(function_id, &[], Some(u128::MAX))
             .expect("should run");
 
         // After compiling and inserting the program, we should be able to run it.
         assert_eq!(res.return_value, JitValue::Felt252(Felt::from(42)));
+
+        // The second request for the same key is a cache hit.
+        let cached_executor = cache
+            .compile_and_insert((), &program, OptLevel::default())
+            .unwrap();
+        assert!(Arc::ptr_eq(&executor, &cached_executor));
+        assert_eq!(cache.compilations(), 1);
+    }
+
+    #[test]
+    fn test_aot_concurrent_compile_and_insert() {
+        let native_context = NativeContext::new();
+        let cache = AotProgramCache::new(&native_context);
+
+        let (_, program) = load_cairo! {
+            fn run_test() -> felt252 {
+                42
+            }
+        };
+
+        let executors = thread::scope(|s| {
+            let handles = (0..4)
+                .map(|_| {
+                    s.spawn(|| {
+                        cache
+                            .compile_and_insert((), &program, OptLevel::default())
+                            .unwrap()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        // Only one of the threads compiles the program, the others reuse its executor.
+        assert_eq!(cache.compilations(), 1);
+        assert!(executors.iter().all(|x| Arc::ptr_eq(x, &executors[0])));
+    }
+
+    #[test]
+    fn test_aot_poisoned_entry() {
+        let native_context = NativeContext::new();
+        let cache = AotProgramCache::new(&native_context);
+
+        let (_, program) = load_cairo! {
+            fn run_test() -> felt252 {
+                42
+            }
+        };
+
+        // Simulate a compilation which panicked while holding the entry's lock.
+        let entry = cache.cache.lock().unwrap().entry(()).or_default().clone();
+        thread::spawn(move || {
+            let _guard = entry.lock().unwrap();
+            panic!("compilation failed");
+        })
+        .join()
+        .unwrap_err();
+
+        assert!(cache.get(&()).is_none());
+        let executor = cache
+            .compile_and_insert((), &program, OptLevel::default())
+            .unwrap();
+        assert!(Arc::ptr_eq(&executor, &cache.get(&()).unwrap()));
+        assert_eq!(cache.compilations(), 1);
+    }
+
+    #[test]
+    fn test_aot_cache_dir() {
+        let native_context = NativeContext::new();
+        let cache_dir = tempfile::tempdir().unwrap();
+
+        let (_, program) = load_cairo! {
+            fn run_test() -> felt252 {
+                42
+            }
+        };
+        let function_id = &program.funcs.first().expect("should have a function").id;
+
+        let run = |native_context: &NativeContext| {
+            let cache = AotProgramCache::new(native_context).with_cache_dir(cache_dir.path());
+            let executor = cache
+                .compile_and_insert((), &program, OptLevel::default())
+                .unwrap();
+            let res = executor
+                .invoke_dynamic(function_id, &[], Some(u128::MAX))
+                .expect("should run");
+            assert_eq!(res.return_value, JitValue::Felt252(Felt::from(42)));
+
+            cache.compilations()
+        };
+        let find_files = |extension: &str| {
+            fs::read_dir(cache_dir.path())
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|x| x == extension))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(&native_context), 1);
+        let [library_path] = &find_files(SHARED_LIBRARY_EXT)[..] else {
+            panic!("the cache directory should contain a single library");
+        };
+        let modified = fs::metadata(library_path).unwrap().modified().unwrap();
+
+        // A second cache finds the library on disk and doesn't recompile it.
+        assert_eq!(run(&native_context), 0);
+        assert_eq!(
+            fs::metadata(library_path).unwrap().modified().unwrap(),
+            modified
+        );
+
+        // Libraries built by other versions have a different name, so they're never loaded.
+        let cache = AotProgramCache::<()>::new(&native_context);
+        let current_name = format!(
+            "lib{}",
+            cache.program_hash(CACHE_VERSION, &program, OptLevel::default())
+        );
+        let older_name = format!(
+            "lib{}",
+            cache.program_hash("cairo-native-0.0.0", &program, OptLevel::default())
+        );
+        assert_eq!(
+            library_path.file_stem().unwrap().to_str().unwrap(),
+            current_name
+        );
+        assert_ne!(current_name, older_name);
+
+        // Libraries compiled with different options are stored separately.
+        let traced_context = NativeContext::new().with_trace_statements(true);
+        assert_eq!(run(&traced_context), 1);
+        assert_eq!(run(&traced_context), 0);
+        assert_eq!(find_files(SHARED_LIBRARY_EXT).len(), 2);
+        assert_eq!(run(&native_context), 0);
     }
 }
@@ -45,14 +45,19 @@
 //! [BFS algorithm]: https://en.wikipedia.org/wiki/Breadth-first_search
 
 use crate::{
+    block_ext::BlockExt,
     debug_info::DebugLocations,
     error::Error,
     libfuncs::{BranchArg, LibfuncBuilder, LibfuncHelper},
     metadata::{
         gas::{GasCost, GasMetadata},
+        runtime_bindings::RuntimeBindingsMeta,
         tail_recursion::TailRecursionMeta,
+        trace_statements::TraceStatementsMeta,
+        trap_analysis::TrapAnalysisMeta,
         MetadataStorage,
     },
+    statistics::CompilationStats,
     types::TypeBuilder,
     utils::generate_function_name,
 };
@@ -61,10 +66,11 @@ use cairo_lang_sierra::{
     edit_state,
     extensions::{
         core::{CoreLibfunc, CoreType},
+        lib_func::BranchSignature,
         ConcreteLibfunc,
     },
-    ids::{ConcreteTypeId, VarId},
-    program::{Function, Invocation, Program, Statement, StatementIdx},
+    ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId, VarId},
+    program::{Function, GenericArg, Invocation, Program, Statement, StatementIdx},
     program_registry::ProgramRegistry,
 };
 use itertools::Itertools;
@@ -86,6 +92,7 @@ use std::{
     cell::Cell,
     collections::{hash_map::Entry, BTreeMap, HashMap, HashSet},
     ops::Deref,
+    time::{Duration, Instant},
 };
 
 /// The [BlockStorage] type is used to map each statement into its own entry block (on the right),
@@ -116,7 +123,107 @@ pub fn compile(
     metadata: &mut MetadataStorage,
     debug_info: Option<&DebugLocations>,
 ) -> Result<(), Error> {
-    for function in &program.funcs {
+    compile_functions(
+        context,
+        module,
+        program,
+        registry,
+        metadata,
+        debug_info,
+        |_| true,
+    )
+}
+
+/// Run the compiler on the functions reachable from the given entry points.
+///
+/// Works like [compile](self::compile), but skips every function which can't be called (directly
+/// or transitively) from `entry_points`. This is useful when only a few of a program's functions
+/// are going to be invoked, like when running a single contract entry point.
+pub fn compile_entry_points(
+    context: &Context,
+    module: &Module,
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    metadata: &mut MetadataStorage,
+    debug_info: Option<&DebugLocations>,
+    entry_points: &[FunctionId],
+) -> Result<(), Error> {
+    let reachable = find_reachable_functions(program, registry, entry_points)?;
+    compile_functions(
+        context,
+        module,
+        program,
+        registry,
+        metadata,
+        debug_info,
+        |function| reachable.contains(&function.id),
+    )
+}
+
+/// A libfunc declaration which the compiler doesn't know how to build.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsupportedLibfunc {
+    /// The concrete libfunc's id, as declared in the program.
+    pub id: ConcreteLibfuncId,
+    /// The generic libfunc the declaration is an instance of.
+    pub generic_id: GenericLibfuncId,
+    /// The generic arguments of the declaration.
+    pub generic_args: Vec<GenericArg>,
+    /// Every statement which invokes the libfunc.
+    pub statements: Vec<StatementIdx>,
+}
+
+/// Find every libfunc declaration in the program which [compile] would reject.
+///
+/// Compilation fails when reaching any of them, so this can be used to check whether a program is
+/// supported (and fall back to something else otherwise) before attempting to compile it.
+pub fn find_unsupported_libfuncs(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> Result<Vec<UnsupportedLibfunc>, Error> {
+    let mut unsupported = Vec::new();
+    for declaration in &program.libfunc_declarations {
+        if registry.get_libfunc(&declaration.id)?.is_supported() {
+            continue;
+        }
+
+        unsupported.push(UnsupportedLibfunc {
+            id: declaration.id.clone(),
+            generic_id: declaration.long_id.generic_id.clone(),
+            generic_args: declaration.long_id.generic_args.clone(),
+            statements: program
+                .statements
+                .iter()
+                .enumerate()
+                .filter_map(|(statement_idx, statement)| match statement {
+                    Statement::Invocation(invocation)
+                        if invocation.libfunc_id == declaration.id =>
+                    {
+                        Some(StatementIdx(statement_idx))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        });
+    }
+
+    Ok(unsupported)
+}
+
+fn compile_functions(
+    context: &Context,
+    module: &Module,
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    metadata: &mut MetadataStorage,
+    debug_info: Option<&DebugLocations>,
+    filter: impl Fn(&Function) -> bool,
+) -> Result<(), Error> {
+    metadata.insert(TrapAnalysisMeta::new(find_trapping_functions(
+        program, registry,
+    )?));
+
+    for function in program.funcs.iter().filter(|function| filter(function)) {
         tracing::info!("Compiling function `{}`.", function.id);
         compile_func(
             context,
@@ -148,6 +255,7 @@ fn compile_func(
     metadata: &mut MetadataStorage,
     debug_info: Option<&DebugLocations>,
 ) -> Result<(), Error> {
+    let compilation_start = Instant::now();
     let region = Region::new();
     let blocks_arena = Bump::new();
 
@@ -225,6 +333,8 @@ fn compile_func(
     let (entry_block, blocks) = generate_function_structure(
         context, module, &region, registry, function, statements, metadata,
     )?;
+    let types_time = compilation_start.elapsed();
+    let mut libfuncs_time = Duration::ZERO;
 
     tracing::debug!("Generating the function implementation.");
     // Workaround for the `entry block of region may not have predecessors` error:
@@ -337,6 +447,30 @@ fn compile_func(
                         format!("{}(stmt_idx={})", invocation.libfunc_id, statement_idx);
 
                     let (state, _) = edit_state::take_args(state, invocation.args.iter())?;
+                    let concrete_libfunc = registry.get_libfunc(&invocation.libfunc_id)?;
+
+                    let mut branches = generate_branching_targets(
+                        &blocks,
+                        statements,
+                        statement_idx,
+                        invocation,
+                        &state,
+                    );
+                    if metadata.get::<TraceStatementsMeta>().is_some() {
+                        branches = generate_trace_blocks(
+                            context,
+                            module,
+                            registry,
+                            metadata,
+                            &region,
+                            &blocks_arena,
+                            &pre_entry_block,
+                            statement_idx,
+                            invocation,
+                            concrete_libfunc.branch_signatures(),
+                            branches,
+                        )?;
+                    }
 
                     let helper = LibfuncHelper {
                         module,
@@ -344,21 +478,16 @@ fn compile_func(
                         region: &region,
                         blocks_arena: &blocks_arena,
                         last_block: Cell::new(block),
-                        branches: generate_branching_targets(
-                            &blocks,
-                            statements,
-                            statement_idx,
-                            invocation,
-                            &state,
-                        ),
+                        branches,
                         results: invocation
                             .branches
                             .iter()
                             .map(|x| vec![Cell::new(None); x.results.len()])
                             .collect::<Vec<_>>(),
+                        return_types: &return_types,
+                        emitted_trap: Cell::new(false),
                     };
 
-                    let concrete_libfunc = registry.get_libfunc(&invocation.libfunc_id)?;
                     if let Some(target) = concrete_libfunc.is_function_call() {
                         if target == &function.id && state.is_empty() {
                             // TODO: Defer insertions until after the recursion has been confirmed
@@ -404,23 +533,33 @@ fn compile_func(
                         }
                     }
 
-                    concrete_libfunc.build(
-                        context,
-                        registry,
-                        block,
-                        Location::name(
+                    let libfunc_start = Instant::now();
+                    concrete_libfunc
+                        .build(
                             context,
-                            &libfunc_name,
-                            debug_info
-                                .and_then(|debug_info| {
-                                    debug_info.statements.get(&statement_idx).copied()
-                                })
-                                .unwrap_or_else(|| Location::unknown(context)),
-                        ),
-                        &helper,
-                        metadata,
-                    )?;
+                            registry,
+                            block,
+                            Location::name(
+                                context,
+                                &libfunc_name,
+                                statement_location(context, debug_info, statement_idx),
+                            ),
+                            &helper,
+                            metadata,
+                        )
+                        .map_err(|e| Error::LibfuncBuild {
+                            id: invocation.libfunc_id.id,
+                            name: invocation.libfunc_id.to_string(),
+                            statement_idx: statement_idx.0,
+                            source: Box::new(e),
+                        })?;
+                    libfuncs_time += libfunc_start.elapsed();
                     assert!(block.terminator().is_some());
+                    // Calls to functions which aren't known to trap don't check for traps.
+                    assert!(
+                        !helper.emitted_trap.get() || concrete_libfunc.may_trap(),
+                        "libfunc `{libfunc_name}` emitted a trap, so `may_trap` must return true"
+                    );
 
                     if let Some(tailrec_meta) = metadata.remove::<TailRecursionMeta>() {
                         if let Some(return_block) = tailrec_meta.return_target() {
@@ -459,7 +598,7 @@ fn compile_func(
                     let location = Location::name(
                         context,
                         &format!("return(stmt_idx={})", statement_idx),
-                        Location::unknown(context),
+                        statement_location(context, debug_info, statement_idx),
                     );
 
                     let (_, mut values) = edit_state::take_args(state, var_ids.iter())?;
@@ -469,7 +608,7 @@ fn compile_func(
                         let location = Location::name(
                             context,
                             &format!("return(stmt_idx={}, tail_recursion)", statement_idx),
-                            Location::unknown(context),
+                            statement_location(context, debug_info, statement_idx),
                         );
                         // Perform tail recursion.
                         for counter_idx in tailrec_state.into_values() {
@@ -673,6 +812,12 @@ fn compile_func(
         Location::unknown(context),
     ));
 
+    if let Some(stats) = metadata.get_mut::<CompilationStats>() {
+        stats.types_time += types_time;
+        stats.libfuncs_time += libfuncs_time;
+        stats.statements_time += compilation_start.elapsed() - types_time - libfuncs_time;
+    }
+
     tracing::debug!("Done generating function {}.", function.id);
     Ok(())
 }
@@ -885,6 +1030,109 @@ fn extract_types<'c: 'a, 'a>(
     })
 }
 
+/// Return the Cairo location of a statement, falling back to its index within the Sierra program
+/// when there's no Cairo debug info.
+///
+/// The fallback is not behind an option because locations don't change the generated code; they
+/// are only emitted when the module is printed with debug info (or lowered with debug passes).
+fn statement_location<'c>(
+    context: &'c Context,
+    debug_info: Option<&DebugLocations<'c>>,
+    statement_idx: StatementIdx,
+) -> Location<'c> {
+    debug_info
+        .and_then(|debug_info| debug_info.statements.get(&statement_idx).copied())
+        .unwrap_or_else(|| Location::new(context, "program.sierra", statement_idx.0, 0))
+}
+
+/// Find every function which may be invoked when calling any of `entry_points`, including the
+/// entry points themselves.
+fn find_reachable_functions(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    entry_points: &[FunctionId],
+) -> Result<HashSet<FunctionId>, Error> {
+    let mut reachable = HashSet::new();
+    let mut queue = entry_points.to_vec();
+
+    while let Some(function_id) = queue.pop() {
+        if !reachable.insert(function_id.clone()) {
+            continue;
+        }
+
+        let function = registry.get_function(&function_id)?;
+        foreach_statement_in_function::<_, Error>(
+            &program.statements,
+            function.entry_point,
+            (),
+            |statement_idx, ()| {
+                Ok(match &program.statements[statement_idx.0] {
+                    Statement::Invocation(invocation) => {
+                        let libfunc = registry.get_libfunc(&invocation.libfunc_id)?;
+                        if let Some(callee_id) = libfunc.is_function_call() {
+                            queue.push(callee_id.clone());
+                        }
+
+                        vec![(); invocation.branches.len()]
+                    }
+                    Statement::Return(_) => Vec::new(),
+                })
+            },
+        )?;
+    }
+
+    Ok(reachable)
+}
+
+/// Find every function which may trap, either by itself or by calling a function which may trap.
+fn find_trapping_functions(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> Result<HashSet<FunctionId>, Error> {
+    let mut callers = HashMap::<FunctionId, Vec<FunctionId>>::new();
+    let mut queue = Vec::new();
+
+    for function in &program.funcs {
+        let mut may_trap = false;
+        foreach_statement_in_function::<_, Error>(
+            &program.statements,
+            function.entry_point,
+            (),
+            |statement_idx, ()| {
+                Ok(match &program.statements[statement_idx.0] {
+                    Statement::Invocation(invocation) => {
+                        let libfunc = registry.get_libfunc(&invocation.libfunc_id)?;
+                        may_trap |= libfunc.may_trap();
+                        if let Some(callee_id) = libfunc.is_function_call() {
+                            callers
+                                .entry(callee_id.clone())
+                                .or_default()
+                                .push(function.id.clone());
+                        }
+
+                        vec![(); invocation.branches.len()]
+                    }
+                    Statement::Return(_) => Vec::new(),
+                })
+            },
+        )?;
+
+        if may_trap {
+            queue.push(function.id.clone());
+        }
+    }
+
+    // Every caller of a function which may trap may trap too.
+    let mut trapping = HashSet::new();
+    while let Some(function_id) = queue.pop() {
+        if trapping.insert(function_id.clone()) {
+            queue.extend(callers.get(&function_id).into_iter().flatten().cloned());
+        }
+    }
+
+    Ok(trapping)
+}
+
 fn foreach_statement_in_function<S, E>(
     statements: &[Statement],
     entry_point: StatementIdx,
@@ -981,3 +1229,111 @@ where
         })
         .collect()
 }
+
+/// Redirect every branch of an invocation through a new block which reports the branch's results
+/// to the runtime's trace hook before jumping into the original target.
+///
+/// The results are stored in stack slots allocated in the init block, so that tracing
+/// tail-recursive functions doesn't grow the stack.
+#[allow(clippy::too_many_arguments)]
+fn generate_trace_blocks<'ctx, 'this>(
+    context: &'ctx Context,
+    module: &'this Module<'ctx>,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    metadata: &mut MetadataStorage,
+    region: &'this Region<'ctx>,
+    blocks_arena: &'this Bump,
+    init_block: &'this Block<'ctx>,
+    statement_idx: StatementIdx,
+    invocation: &Invocation,
+    branch_signatures: &[BranchSignature],
+    branches: Vec<(&'this Block<'ctx>, Vec<BranchArg<'ctx, 'this>>)>,
+) -> Result<Vec<(&'this Block<'ctx>, Vec<BranchArg<'ctx, 'this>>)>, Error>
+where
+    'this: 'ctx,
+{
+    let location = Location::name(
+        context,
+        &format!("trace(stmt_idx={})", statement_idx),
+        Location::unknown(context),
+    );
+
+    invocation
+        .branches
+        .iter()
+        .zip(branch_signatures)
+        .zip(branches)
+        .map(|((branch, branch_signature), (successor, operands))| {
+            let result_types = branch_signature
+                .vars
+                .iter()
+                .map(|var_info| {
+                    Ok((
+                        registry.get_type(&var_info.ty)?.build(
+                            context,
+                            module,
+                            registry,
+                            metadata,
+                            &var_info.ty,
+                        )?,
+                        location,
+                    ))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            let trace_block: &'this Block<'ctx> =
+                blocks_arena.alloc(region.append_block(Block::new(&result_types)));
+
+            for (idx, (var_id, var_info)) in branch
+                .results
+                .iter()
+                .zip(&branch_signature.vars)
+                .enumerate()
+            {
+                let layout = registry.get_type(&var_info.ty)?.layout(registry)?;
+                let value: Value = trace_block.argument(idx)?.into();
+
+                let value_ptr =
+                    init_block.alloca1(context, location, value.r#type(), Some(layout.align()))?;
+                trace_block.store(context, location, value_ptr, value, Some(layout.align()))?;
+
+                let statement_idx_value =
+                    trace_block.const_int(context, location, statement_idx.0, 64)?;
+                let var_id_value = trace_block.const_int(context, location, var_id.id, 64)?;
+                let type_id_value = trace_block.const_int(context, location, var_info.ty.id, 64)?;
+                let value_size = trace_block.const_int(context, location, layout.size(), 64)?;
+
+                metadata
+                    .get_mut::<RuntimeBindingsMeta>()
+                    .ok_or(Error::MissingMetadata)?
+                    .trace(
+                        context,
+                        module,
+                        trace_block,
+                        statement_idx_value,
+                        var_id_value,
+                        type_id_value,
+                        value_ptr,
+                        value_size,
+                        location,
+                    )?;
+            }
+
+            let destination_operands = operands
+                .iter()
+                .map(|op| {
+                    Ok(match op {
+                        BranchArg::External(x) => *x,
+                        BranchArg::Returned(i) => trace_block.argument(*i)?.into(),
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            trace_block.append_operation(cf::br(successor, &destination_operands, location));
+
+            Ok((
+                trace_block,
+                (0..branch.results.len()).map(BranchArg::Returned).collect(),
+            ))
+        })
+        .collect()
+}
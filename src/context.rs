@@ -1,4 +1,4 @@
-use std::sync::OnceLock;
+use std::{sync::OnceLock, time::Instant};
 
 use crate::{
     debug_info::DebugLocations,
@@ -7,13 +7,17 @@ use crate::{
     metadata::{
         gas::{GasMetadata, MetadataComputationConfig},
         runtime_bindings::RuntimeBindingsMeta,
+        trace_statements::TraceStatementsMeta,
         MetadataStorage,
     },
     module::NativeModule,
-    utils::run_pass_manager,
+    statistics::{count_operations, CompilationStats},
+    utils::run_pass_manager_with_options,
+    OptLevel,
 };
 use cairo_lang_sierra::{
     extensions::core::{CoreLibfunc, CoreType},
+    ids::FunctionId,
     program::Program,
     program_registry::ProgramRegistry,
 };
@@ -36,6 +40,9 @@ use melior::{
 #[derive(Debug, Eq, PartialEq)]
 pub struct NativeContext {
     context: Context,
+    opt_level: OptLevel,
+    pass_pipeline: Option<String>,
+    trace_statements: bool,
 }
 
 unsafe impl Send for NativeContext {}
@@ -50,7 +57,48 @@ impl Default for NativeContext {
 impl NativeContext {
     pub fn new() -> Self {
         let context = initialize_mlir();
-        Self { context }
+        Self {
+            context,
+            opt_level: OptLevel::None,
+            pass_pipeline: None,
+            trace_statements: false,
+        }
+    }
+
+    /// Select the MLIR optimization passes run before lowering into the LLVM dialect.
+    ///
+    /// This is independent from the LLVM optimization level, which is chosen when creating the
+    /// executor.
+    pub fn with_opt_level(mut self, opt_level: OptLevel) -> Self {
+        self.opt_level = opt_level;
+        self
+    }
+
+    /// Run a custom textual pass pipeline, like `builtin.module(inline,canonicalize)`, instead of
+    /// the optimization passes selected by the optimization level.
+    pub fn with_pass_pipeline(mut self, pass_pipeline: &str) -> Self {
+        self.pass_pipeline = Some(pass_pipeline.to_string());
+        self
+    }
+
+    /// Report every variable produced by a statement to the runtime's trace hook.
+    ///
+    /// Meant for debugging miscompilations: the executor's trace hook receives the statement index,
+    /// the variable and its type along with the value's raw bytes.
+    pub fn with_trace_statements(mut self, trace_statements: bool) -> Self {
+        self.trace_statements = trace_statements;
+        self
+    }
+
+    /// Describes every option that changes the generated code.
+    ///
+    /// On-disk caches include it in their keys so that code compiled with different options is
+    /// never mixed up.
+    pub(crate) fn options_key(&self) -> String {
+        format!(
+            "opt_level={:?};pass_pipeline={:?};trace_statements={}",
+            self.opt_level, self.pass_pipeline, self.trace_statements
+        )
     }
 
     pub fn context(&self) -> &Context {
@@ -59,10 +107,33 @@ impl NativeContext {
 
     /// Compiles a sierra program into MLIR and then lowers to LLVM.
     /// Returns the corresponding NativeModule struct.
+    ///
+    /// The output is deterministic: compiling the same program with the same options always
+    /// yields the same module.
     pub fn compile(
         &self,
         program: &Program,
         debug_locations: Option<DebugLocations>,
+    ) -> Result<NativeModule, Error> {
+        self.compile_impl(program, None, debug_locations)
+    }
+
+    /// Compiles only the functions reachable from the given entry points into MLIR and then lowers
+    /// to LLVM. Returns the corresponding NativeModule struct.
+    pub fn compile_entry_points(
+        &self,
+        program: &Program,
+        entry_points: &[FunctionId],
+        debug_locations: Option<DebugLocations>,
+    ) -> Result<NativeModule, Error> {
+        self.compile_impl(program, Some(entry_points), debug_locations)
+    }
+
+    fn compile_impl(
+        &self,
+        program: &Program,
+        entry_points: Option<&[FunctionId]>,
+        debug_locations: Option<DebugLocations>,
     ) -> Result<NativeModule, Error> {
         static INITIALIZED: OnceLock<()> = OnceLock::new();
         INITIALIZED.get_or_init(|| unsafe {
@@ -99,35 +170,50 @@ impl NativeContext {
 
         let mut module = Module::from_operation(op).expect("module failed to create");
 
-        let has_gas_builtin = program
-            .type_declarations
-            .iter()
-            .any(|decl| decl.long_id.generic_id.0.as_str() == "GasBuiltin");
-
         let mut metadata = MetadataStorage::new();
         // Make the runtime library available.
         metadata.insert(RuntimeBindingsMeta::default());
         // We assume that GasMetadata will be always present when the program uses the gas builtin.
-        let gas_metadata = if has_gas_builtin {
-            GasMetadata::new(program, Some(MetadataComputationConfig::default()))
-        } else {
-            GasMetadata::new(program, None)
-        }?;
+        let gas_metadata = GasMetadata::for_program(program)?;
         // Unwrapping here is not necessary since the insertion will only fail if there was
         // already some metadata of the same type.
         metadata.insert(gas_metadata);
+        if self.trace_statements {
+            metadata.insert(TraceStatementsMeta);
+        }
 
         // Create the Sierra program registry
         let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
 
-        crate::compile(
-            &self.context,
-            &module,
-            program,
-            &registry,
-            &mut metadata,
-            debug_locations.as_ref(),
-        )?;
+        // The compiler adds the time spent in each phase to the stats while they're stored here.
+        metadata.insert(CompilationStats::from_program(program, &registry)?);
+        let compilation_start = Instant::now();
+
+        match entry_points {
+            Some(entry_points) => crate::compile_entry_points(
+                &self.context,
+                &module,
+                program,
+                &registry,
+                &mut metadata,
+                debug_locations.as_ref(),
+                entry_points,
+            )?,
+            None => crate::compile(
+                &self.context,
+                &module,
+                program,
+                &registry,
+                &mut metadata,
+                debug_locations.as_ref(),
+            )?,
+        }
+
+        let mut stats = metadata
+            .remove::<CompilationStats>()
+            .ok_or(Error::MissingMetadata)?;
+        stats.compilation_time = compilation_start.elapsed();
+        stats.num_operations = count_operations(&module.as_operation());
 
         if let Ok(x) = std::env::var("NATIVE_DEBUG_DUMP_PREPASS") {
             if x == "1" || x == "true" {
@@ -143,7 +229,15 @@ impl NativeContext {
             }
         }
 
-        run_pass_manager(&self.context, &mut module)?;
+        let passes_start = Instant::now();
+        run_pass_manager_with_options(
+            &self.context,
+            &mut module,
+            self.opt_level,
+            self.pass_pipeline.as_deref(),
+        )?;
+        stats.passes_time = passes_start.elapsed();
+        metadata.insert(stats);
 
         if let Ok(x) = std::env::var("NATIVE_DEBUG_DUMP") {
             if x == "1" || x == "true" {
@@ -187,6 +281,9 @@ impl NativeContext {
 
         let gas_metadata = GasMetadata::new(program, Some(metadata_config))?;
         metadata.insert(gas_metadata);
+        if self.trace_statements {
+            metadata.insert(TraceStatementsMeta);
+        }
 
         // Create the Sierra program registry
         let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
@@ -200,7 +297,12 @@ impl NativeContext {
             None,
         )?;
 
-        run_pass_manager(&self.context, &mut module)?;
+        run_pass_manager_with_options(
+            &self.context,
+            &mut module,
+            self.opt_level,
+            self.pass_pipeline.as_deref(),
+        )?;
 
         Ok(NativeModule::new(module, registry, metadata))
     }
@@ -219,3 +321,126 @@ pub fn initialize_mlir() -> Context {
     register_all_llvm_translations(&context);
     context
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        executor::JitNativeExecutor,
+        utils::test::{jit_enum, jit_struct, load_cairo},
+        values::JitValue,
+    };
+    use starknet_types_core::felt::Felt;
+    use test_case::test_case;
+
+    fn run_fib(native_context: NativeContext) -> Result<JitValue, Error> {
+        let (_, program) = load_cairo! {
+            fn fib(a: felt252, b: felt252, n: felt252) -> felt252 {
+                match n {
+                    0 => a,
+                    _ => fib(b, a + b, n - 1),
+                }
+            }
+
+            #[inline(never)]
+            fn double(value: felt252) -> felt252 {
+                value * 2
+            }
+
+            fn run_test() -> felt252 {
+                double(fib(0, 1, 10))
+            }
+        };
+
+        let module = native_context.compile(&program, None)?;
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+        let function_id = &program
+            .funcs
+            .iter()
+            .find(|f| {
+                f.id.debug_name
+                    .as_deref()
+                    .is_some_and(|name| name.ends_with("::run_test"))
+            })
+            .unwrap()
+            .id;
+
+        Ok(executor
+            .invoke_dynamic(function_id, &[], None)?
+            .return_value)
+    }
+
+    #[test_case(OptLevel::None)]
+    #[test_case(OptLevel::Less)]
+    #[test_case(OptLevel::Default)]
+    #[test_case(OptLevel::Aggressive)]
+    fn compile_with_opt_level(opt_level: OptLevel) {
+        let result = run_fib(NativeContext::new().with_opt_level(opt_level)).unwrap();
+        assert_eq!(
+            result,
+            jit_enum!(0, jit_struct!(JitValue::Felt252(Felt::from(110))))
+        );
+    }
+
+    #[test]
+    fn compile_with_pass_pipeline() {
+        let result =
+            run_fib(NativeContext::new().with_pass_pipeline("builtin.module(inline,canonicalize)"))
+                .unwrap();
+        assert_eq!(
+            result,
+            jit_enum!(0, jit_struct!(JitValue::Felt252(Felt::from(110))))
+        );
+
+        assert!(run_fib(NativeContext::new().with_pass_pipeline("not-a-pass")).is_err());
+    }
+
+    #[test]
+    fn compile_is_deterministic() {
+        let (_, program) = load_cairo! {
+            use core::dict::Felt252DictTrait;
+
+            #[derive(Drop)]
+            enum Shape {
+                Circle: u32,
+                Rectangle: (u32, u32),
+            }
+
+            fn area(shape: @Shape) -> u32 {
+                match shape {
+                    Shape::Circle(r) => 3 * *r * *r,
+                    Shape::Rectangle((w, h)) => *w * *h,
+                }
+            }
+
+            fn run_test() -> felt252 {
+                let shapes = array![Shape::Circle(2), Shape::Rectangle((3, 4))];
+                let mut dict: Felt252Dict<u32> = Default::default();
+
+                let mut i = 0;
+                while i < shapes.len() {
+                    dict.insert(i.into(), area(shapes[i]));
+                    i += 1;
+                };
+
+                (dict.get(0) + dict.get(1)).into()
+            }
+        };
+
+        let outputs = (0..5)
+            .map(|_| {
+                let native_context = NativeContext::new();
+                let module = native_context.compile(&program, None).unwrap();
+                module
+                    .module()
+                    .as_operation()
+                    .to_string_with_flags(
+                        OperationPrintingFlags::new().enable_debug_info(true, false),
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(outputs.windows(2).all(|x| x[0] == x[1]));
+    }
+}
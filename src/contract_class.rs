@@ -0,0 +1,197 @@
+//! # Starknet contract classes
+//!
+//! Support for Sierra contract classes (the `.contract_class.json` artifacts generated by the
+//! Starknet compiler) as compiler input. The felt-encoded Sierra program is decoded and the entry
+//! point tables are resolved into function ids so that contracts can be invoked by selector.
+
+use crate::{error::Error, utils::find_entry_point_by_idx};
+use cairo_lang_sierra::{ids::FunctionId, program::Program};
+use cairo_lang_starknet_classes::contract_class::ContractClass;
+use starknet_types_core::felt::Felt;
+
+/// The kind of a contract class entry point.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EntryPointKind {
+    External,
+    L1Handler,
+    Constructor,
+}
+
+/// A contract class entry point, resolved against its Sierra program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntryPoint {
+    pub kind: EntryPointKind,
+    pub selector: Felt,
+    /// Index of the entry point's function within the Sierra program.
+    pub function_idx: usize,
+    pub function_id: FunctionId,
+}
+
+/// A contract class with its Sierra program decoded.
+#[derive(Clone, Debug)]
+pub struct NativeContractClass {
+    program: Program,
+    entry_points: Vec<EntryPoint>,
+}
+
+impl NativeContractClass {
+    /// Decode the Sierra program of a contract class and resolve its entry points.
+    pub fn new(contract_class: &ContractClass) -> Result<Self, Error> {
+        let program = contract_class
+            .extract_sierra_program()
+            .map_err(|e| Error::InvalidContractClass(e.to_string()))?;
+
+        let entry_points_by_type = &contract_class.entry_points_by_type;
+        let entry_points = [
+            (EntryPointKind::External, &entry_points_by_type.external),
+            (EntryPointKind::L1Handler, &entry_points_by_type.l1_handler),
+            (
+                EntryPointKind::Constructor,
+                &entry_points_by_type.constructor,
+            ),
+        ]
+        .into_iter()
+        .flat_map(|(kind, entry_points)| entry_points.iter().map(move |x| (kind, x)))
+        .map(|(kind, entry_point)| {
+            let function =
+                find_entry_point_by_idx(&program, entry_point.function_idx).ok_or_else(|| {
+                    Error::InvalidContractClass(format!(
+                        "entry point function index {} is out of range",
+                        entry_point.function_idx
+                    ))
+                })?;
+
+            Ok(EntryPoint {
+                kind,
+                selector: Felt::from_bytes_be_slice(&entry_point.selector.to_bytes_be()),
+                function_idx: entry_point.function_idx,
+                function_id: function.id.clone(),
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            program,
+            entry_points,
+        })
+    }
+
+    /// Parse a contract class from its JSON representation.
+    #[cfg(feature = "with-serde")]
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let contract_class: ContractClass =
+            serde_json::from_str(json).map_err(|e| Error::InvalidContractClass(e.to_string()))?;
+
+        Self::new(&contract_class)
+    }
+
+    /// Return the decoded Sierra program.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Return every entry point of the contract class.
+    pub fn entry_points(&self) -> &[EntryPoint] {
+        &self.entry_points
+    }
+
+    /// Find the entry point with the given selector.
+    pub fn find_entry_point(&self, selector: &Felt) -> Option<&EntryPoint> {
+        self.entry_points.iter().find(|x| &x.selector == selector)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        context::NativeContext, executor::JitNativeExecutor, starknet::DummySyscallHandler,
+    };
+    use cairo_lang_compiler::{db::RootDatabase, project::setup_project, CompilerConfig};
+    use cairo_lang_starknet::{compile::compile_contract_in_prepared_db, starknet_plugin_suite};
+    use std::path::Path;
+
+    fn load_contract(path: &str) -> ContractClass {
+        let mut db = RootDatabase::builder()
+            .detect_corelib()
+            .with_plugin_suite(starknet_plugin_suite())
+            .build()
+            .unwrap();
+        let main_crate_ids = setup_project(&mut db, Path::new(path)).unwrap();
+
+        compile_contract_in_prepared_db(
+            &db,
+            None,
+            main_crate_ids,
+            CompilerConfig {
+                replace_ids: true,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn entry_points_match_contract_class() {
+        let contract_class = load_contract("tests/cases/cairo_vm/contracts/fib.cairo");
+        let native_class = NativeContractClass::new(&contract_class).unwrap();
+
+        let external = &contract_class.entry_points_by_type.external;
+        assert_eq!(native_class.entry_points().len(), external.len());
+        for (entry_point, expected) in native_class.entry_points().iter().zip(external) {
+            assert_eq!(entry_point.kind, EntryPointKind::External);
+            assert_eq!(
+                entry_point.selector,
+                Felt::from_bytes_be_slice(&expected.selector.to_bytes_be())
+            );
+            assert_eq!(entry_point.function_idx, expected.function_idx);
+            assert_eq!(
+                &entry_point.function_id,
+                &find_entry_point_by_idx(native_class.program(), expected.function_idx)
+                    .unwrap()
+                    .id
+            );
+        }
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn invoke_by_selector() {
+        let contract_class = load_contract("tests/cases/cairo_vm/contracts/fib.cairo");
+        let selector = Felt::from_bytes_be_slice(
+            &contract_class.entry_points_by_type.external[0]
+                .selector
+                .to_bytes_be(),
+        );
+
+        let json = serde_json::to_string(&contract_class).unwrap();
+        let native_class = NativeContractClass::from_json(&json).unwrap();
+        let entry_point = native_class.find_entry_point(&selector).unwrap();
+        assert!(native_class.find_entry_point(&Felt::ZERO).is_none());
+
+        let context = NativeContext::new();
+        let module = context.compile(native_class.program(), None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, Default::default());
+
+        let result = executor
+            .invoke_contract_dynamic(
+                &entry_point.function_id,
+                &[1.into(), 1.into(), 10.into()],
+                Some(u128::MAX),
+                DummySyscallHandler,
+            )
+            .unwrap();
+
+        assert!(!result.failure_flag);
+        assert_eq!(result.return_values, [89.into()]);
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn invalid_json() {
+        assert!(matches!(
+            NativeContractClass::from_json("{}"),
+            Err(Error::InvalidContractClass(_))
+        ));
+    }
+}
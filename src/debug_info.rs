@@ -160,9 +160,18 @@ fn extract_location_from_stable_loc<'c>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{context::NativeContext, utils::test::load_cairo};
+    use crate::{
+        context::NativeContext,
+        metadata::{runtime_bindings::RuntimeBindingsMeta, MetadataStorage},
+        utils::test::load_cairo,
+    };
     use cairo_lang_semantic::test_utils::setup_test_function;
+    use cairo_lang_sierra::{
+        extensions::core::{CoreLibfunc, CoreType},
+        program_registry::ProgramRegistry,
+    };
     use cairo_lang_sierra_generator::db::SierraGenGroup;
+    use melior::ir::{operation::OperationPrintingFlags, Module};
     use rstest::*;
 
     #[fixture]
@@ -252,4 +261,30 @@ mod test {
             .unwrap()
             .contains("run_test")));
     }
+
+    #[test]
+    fn test_statement_locations_without_debug_info() {
+        let (_, program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252) -> felt252 {
+                a * b + 1
+            }
+        };
+
+        let native_context = NativeContext::new();
+        let context = native_context.context();
+        let module = Module::new(Location::unknown(context));
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+        let mut metadata = MetadataStorage::new();
+        metadata.insert(RuntimeBindingsMeta::default()).unwrap();
+
+        crate::compile(context, &module, &program, &registry, &mut metadata, None).unwrap();
+
+        let mlir = module
+            .as_operation()
+            .to_string_with_flags(OperationPrintingFlags::new().enable_debug_info(true, false))
+            .unwrap();
+        for statement_idx in 0..program.statements.len() {
+            assert!(mlir.contains(&format!("\"program.sierra\":{statement_idx}:0")));
+        }
+    }
 }
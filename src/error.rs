@@ -4,6 +4,8 @@ use cairo_lang_sierra::extensions::modules::utils::Range;
 use cairo_lang_sierra::{
     edit_state::EditStateError, ids::ConcreteTypeId, program_registry::ProgramRegistryError,
 };
+use itertools::Itertools;
+use starknet_types_core::felt::Felt;
 use std::{alloc::LayoutError, num::TryFromIntError};
 use thiserror::Error;
 
@@ -26,6 +28,12 @@ pub enum Error {
     #[error("a syscall handler was expected but was not provided")]
     MissingSyscallHandler,
 
+    #[error("the function returns multiple values but no return pointer was provided")]
+    MissingReturnPointer,
+
+    #[error("more arguments were provided than the function's signature accepts")]
+    TooManyArguments,
+
     #[error(transparent)]
     LayoutErrorPolyfill(#[from] crate::utils::LayoutError),
 
@@ -53,14 +61,90 @@ pub enum Error {
     #[error(transparent)]
     GasMetadataError(#[from] GasMetadataError),
 
+    #[error("failed to compile the cairo project:\n{0}")]
+    CairoCompilation(String),
+
+    #[error("invalid contract class: {0}")]
+    InvalidContractClass(String),
+
     #[error("llvm error")]
     LLVMCompileError(String),
 
     #[error("cairo const data mismatch")]
     ConstDataMismatch,
+
+    #[error("unsupported libfunc '{0}'")]
+    UnsupportedLibfunc(String),
+
+    #[error("unsupported type '{0}'")]
+    UnsupportedType(String),
+
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    LibraryLoadError(#[from] libloading::Error),
+
+    #[error("failed to spawn the invocation thread: {0}")]
+    SpawnThread(std::io::Error),
+
+    #[error("the program trapped with code {code}: {message}")]
+    Trap { code: u64, message: String },
+
+    #[error("the program panicked with [{}]", format_panic_data(.0))]
+    Panic(Vec<Felt>),
+
+    #[error("error building libfunc '{name}' (id {id}) at statement {statement_idx}: {source}")]
+    LibfuncBuild {
+        id: u64,
+        name: String,
+        statement_idx: usize,
+        source: Box<Error>,
+    },
+}
+
+/// Codes passed to the runtime's trap function when the program reaches an invalid state.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u64)]
+pub enum TrapCode {
+    InvalidEnumTag = 1,
+    ZeroVariantEnumMatch = 2,
+    AllocationFailed = 3,
+    DebugPrintFailed = 4,
+}
+
+impl TrapCode {
+    pub fn from_code(code: u64) -> Option<Self> {
+        match code {
+            1 => Some(Self::InvalidEnumTag),
+            2 => Some(Self::ZeroVariantEnumMatch),
+            3 => Some(Self::AllocationFailed),
+            4 => Some(Self::DebugPrintFailed),
+            _ => None,
+        }
+    }
+
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidEnumTag => "invalid enum tag",
+            Self::ZeroVariantEnumMatch => "attempt to match a zero-variant enum",
+            Self::AllocationFailed => "memory allocation failed",
+            Self::DebugPrintFailed => "print libfunc invocation failed",
+        }
+    }
 }
 
 impl Error {
+    pub fn make_trap(code: u64) -> Self {
+        Self::Trap {
+            code,
+            message: TrapCode::from_code(code)
+                .map(TrapCode::message)
+                .unwrap_or("unknown trap")
+                .to_string(),
+        }
+    }
+
     pub fn make_missing_parameter(ty: &ConcreteTypeId) -> Self {
         Self::MissingParameter(
             ty.debug_name
@@ -69,6 +153,36 @@ impl Error {
                 .unwrap_or_default(),
         )
     }
+
+    pub fn make_unexpected_value(ty: &ConcreteTypeId) -> Self {
+        Self::UnexpectedValue(
+            ty.debug_name
+                .as_ref()
+                .map(|x| x.to_string())
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// Format panic data the same way the Cairo runner does, decoding the felts which are valid short
+/// strings.
+fn format_panic_data(data: &[Felt]) -> String {
+    data.iter()
+        .map(|felt| {
+            let bytes = felt.to_bytes_be();
+            let bytes = &bytes[bytes.iter().position(|&x| x != 0).unwrap_or(bytes.len())..];
+
+            match std::str::from_utf8(bytes) {
+                Ok(text)
+                    if !text.is_empty()
+                        && text.chars().all(|c| c.is_ascii() && !c.is_ascii_control()) =>
+                {
+                    format!("{felt} ('{text}')")
+                }
+                _ => felt.to_string(),
+            }
+        })
+        .join(", ")
 }
 
 #[derive(Error, Debug)]
@@ -82,6 +196,9 @@ pub enum SierraAssertError {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::context::NativeContext;
+    use cairo_lang_sierra::ProgramParser;
+    use cairo_lang_sierra_ap_change::ApChangeError;
 
     #[test]
     fn test_make_missing_parameter() {
@@ -107,4 +224,69 @@ mod test {
             "missing parameter of type ''"
         );
     }
+
+    #[test]
+    fn compile_unknown_type_reference() {
+        // `Ghost` is never declared, so the program can't be validated.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+
+                    libfunc store_temp<Ghost> = store_temp<Ghost>;
+
+                    store_temp<Ghost>([0]) -> ([0]);
+                    return([0]);
+
+                    program::program::run_test@0([0]: felt252) -> (felt252);
+                "#,
+            )
+            .unwrap();
+
+        let native_context = NativeContext::new();
+        // The gas metadata is computed before the compiler builds its registry, so the bad
+        // reference is reported by the AP change computation.
+        assert!(matches!(
+            native_context.compile(&program, None),
+            Err(Error::GasMetadataError(GasMetadataError::ApChangeError(
+                ApChangeError::ProgramRegistryError(_)
+            )))
+        ));
+    }
+
+    #[test]
+    fn compile_unsupported_libfunc() {
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type GasBuiltin = GasBuiltin;
+
+                    libfunc redeposit_gas = redeposit_gas;
+                    libfunc store_temp<GasBuiltin> = store_temp<GasBuiltin>;
+
+                    store_temp<GasBuiltin>([0]) -> ([0]);
+                    redeposit_gas([0]) -> ([0]);
+                    return([0]);
+
+                    program::program::run_test@0([0]: GasBuiltin) -> (GasBuiltin);
+                "#,
+            )
+            .unwrap();
+
+        let native_context = NativeContext::new();
+        let error = native_context.compile(&program, None).unwrap_err();
+        match error {
+            Error::LibfuncBuild {
+                name,
+                statement_idx,
+                source,
+                ..
+            } => {
+                assert_eq!(name, "redeposit_gas");
+                assert_eq!(statement_idx, 1);
+                assert!(matches!(*source, Error::UnsupportedLibfunc(_)));
+            }
+            _ => panic!("unexpected error: {error}"),
+        }
+    }
 }
@@ -17,18 +17,78 @@ pub struct BuiltinStats {
 }
 
 /// The result of the JIT execution.
+///
+/// Panics are returned as a regular `PanicResult` value rather than as an [Error::Panic], so that
+/// callers which inspect the raw return value keep working. Use [ExecutionResult::into_result] to
+/// opt into having them converted to an error.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExecutionResult {
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "crate::values::json::optional_integer")
+    )]
     pub remaining_gas: Option<u128>,
     pub return_value: JitValue,
     pub builtin_stats: BuiltinStats,
+    /// Whether the function's return type is a `core::panics::PanicResult`.
+    ///
+    /// It's computed by the executor from the function's signature, since the return value alone
+    /// can't tell a `PanicResult` apart from a user enum with the same shape. Results serialized
+    /// without it are read as not being panic results.
+    #[cfg_attr(feature = "with-serde", serde(default))]
+    pub is_panic_result: bool,
+}
+
+impl ExecutionResult {
+    /// Split a panic-wrapped return value into either the function's return values or its panic
+    /// data.
+    ///
+    /// Returns `None` if the function doesn't return a `PanicResult`.
+    pub fn panic_result(&self) -> Option<Result<&[JitValue], &[JitValue]>> {
+        if !self.is_panic_result {
+            return None;
+        }
+
+        let JitValue::Enum { tag, value, .. } = &self.return_value else {
+            return None;
+        };
+        match (tag, &**value) {
+            (0, JitValue::Struct { fields, .. }) => Some(Ok(fields.as_slice())),
+            (1, JitValue::Struct { fields, .. }) => match fields.as_slice() {
+                [_, JitValue::Array(panic_data)] => Some(Err(panic_data.as_slice())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Convert the return value into the function's return values, or an [Error::Panic] if it
+    /// panicked.
+    ///
+    /// Return values which aren't a `PanicResult` are returned as is.
+    pub fn into_result(self) -> Result<Vec<JitValue>, Error> {
+        match self.panic_result() {
+            Some(Ok(values)) => Ok(values.to_vec()),
+            Some(Err(panic_data)) => Err(Error::Panic(
+                panic_data
+                    .iter()
+                    .map(|x| match x {
+                        JitValue::Felt252(x) => Ok(*x),
+                        _ => Err(Error::UnexpectedValue("felt252".to_string())),
+                    })
+                    .collect::<Result<_, _>>()?,
+            )),
+            None => Ok(vec![self.return_value]),
+        }
+    }
 }
 
 /// Starknet contract execution result.
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContractExecutionResult {
+    #[cfg_attr(feature = "with-serde", serde(with = "crate::values::json::integer"))]
     pub remaining_gas: u128,
     pub failure_flag: bool,
     pub return_values: Vec<Felt>,
@@ -136,3 +196,49 @@ impl ContractExecutionResult {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::test::{load_cairo_str, run_program};
+
+    #[test]
+    fn test_into_result_panic() {
+        let program = load_cairo_str(
+            r#"
+                fn run_test(value: felt252) -> felt252 {
+                    assert(value != 0, 'boom');
+                    value
+                }
+            "#,
+        );
+
+        let result = run_program(&program, "run_test", &[JitValue::Felt252(Felt::from(3))]);
+        assert_eq!(
+            result.into_result().unwrap(),
+            [JitValue::Felt252(Felt::from(3))]
+        );
+
+        let result = run_program(&program, "run_test", &[JitValue::Felt252(Felt::from(0))]);
+        let error = result.into_result().unwrap_err();
+        assert!(
+            matches!(&error, Error::Panic(data) if data == &[Felt::from_bytes_be_slice(b"boom")])
+        );
+        assert!(error.to_string().contains("('boom')"));
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_deserialize_without_panic_result_flag() {
+        let result: ExecutionResult = serde_json::from_value(serde_json::json!({
+            "remaining_gas": null,
+            "return_value": { "felt252": "0x1" },
+            "builtin_stats": BuiltinStats::default(),
+        }))
+        .unwrap();
+
+        assert_eq!(result.return_value, JitValue::Felt252(Felt::ONE));
+        assert!(!result.is_panic_result);
+        assert_eq!(result.panic_result(), None);
+    }
+}
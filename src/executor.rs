@@ -18,15 +18,18 @@ use cairo_lang_sierra::{
         core::{CoreLibfunc, CoreType, CoreTypeConcrete},
         starknet::StarkNetTypeConcrete,
     },
-    ids::{ConcreteTypeId, FunctionId},
-    program::FunctionSignature,
-    program_registry::{ProgramRegistry, ProgramRegistryError},
+    ids::{ConcreteTypeId, FunctionId, VarId},
+    program::{FunctionSignature, GenericArg, StatementIdx},
+    program_registry::ProgramRegistry,
 };
 use libc::c_void;
 use starknet_types_core::felt::Felt;
 use std::{
     alloc::Layout,
+    any::Any,
     arch::global_asm,
+    cell::Cell,
+    panic::{catch_unwind, AssertUnwindSafe},
     ptr::{addr_of_mut, null_mut, NonNull},
     rc::Rc,
 };
@@ -34,6 +37,25 @@ use std::{
 mod aot;
 mod jit;
 
+/// The runtime function which returns (and clears) the code of the pending trap, if any.
+type TakeTrapFn = unsafe extern "C" fn() -> u64;
+/// The runtime's statement trace hook, see [`TraceEvent`].
+type TraceHookFn = unsafe extern "C" fn(*mut c_void, u64, u64, u64, *const u8, u64);
+/// The runtime function which installs (or removes) the statement trace hook.
+type SetTraceHookFn = unsafe extern "C" fn(Option<TraceHookFn>, *mut c_void);
+
+/// A statement trace event, reported for every variable produced by a statement when the program
+/// is compiled with [`NativeContext::with_trace_statements`](crate::context::NativeContext::with_trace_statements).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceEvent<'a> {
+    pub statement_idx: StatementIdx,
+    pub var_id: VarId,
+    /// The variable's type, which may be looked up in the program registry to decode the value.
+    pub type_id: ConcreteTypeId,
+    /// The raw bytes of the value, using the same layout as the compiled program.
+    pub value: &'a [u8],
+}
+
 #[cfg(target_arch = "aarch64")]
 global_asm!(include_str!("arch/aarch64.s"));
 #[cfg(target_arch = "x86_64")]
@@ -142,6 +164,25 @@ impl<'m> From<JitNativeExecutor<'m>> for NativeExecutor<'m> {
 /// constructs the function call in place.
 ///
 /// To pass the arguments, they are stored in a arena.
+///
+/// The arguments are laid out in the following order:
+///   1. The return pointer, if the return values don't fit in the return registers.
+///   2. The function's parameters, skipping the zero-sized ones. The gas builtin is initialized
+///      from `gas`, the syscall handler from `syscall_handler` and the rest of the builtins are
+///      zero-initialized. Only the remaining (non-builtin) parameters are taken from `args`.
+///
+/// Builtins are likewise removed from the return values, except for the gas builtin which is
+/// reported as the remaining gas.
+///
+/// If the program traps (see `take_trap`), the return values are discarded and an error is
+/// returned instead. Executors must only pass no `take_trap` when the program can't trap.
+///
+/// When `stack_size` is set, the function is run on a dedicated thread with a stack of that size,
+/// which allows deeply recursive programs to complete.
+///
+/// When `trace_hook` is set, it's installed in the runtime for the duration of the invocation and
+/// receives the statement trace events of programs compiled with statement tracing.
+#[allow(clippy::too_many_arguments)]
 fn invoke_dynamic(
     registry: &ProgramRegistry<CoreType, CoreLibfunc>,
     function_ptr: *const c_void,
@@ -149,6 +190,9 @@ fn invoke_dynamic(
     args: &[JitValue],
     gas: u128,
     mut syscall_handler: Option<impl StarknetSyscallHandler>,
+    take_trap: Option<TakeTrapFn>,
+    stack_size: Option<usize>,
+    trace_hook: Option<(SetTraceHookFn, &dyn Fn(&TraceEvent))>,
 ) -> Result<ExecutionResult, Error> {
     tracing::info!("Invoking function with signature: {function_signature:?}.");
     let arena = Bump::new();
@@ -201,15 +245,6 @@ fn invoke_dynamic(
     let mut syscall_handler = syscall_handler
         .as_mut()
         .map(|syscall_handler| StarknetSyscallHandlerCallbacks::new(syscall_handler));
-    // We only care for the previous syscall handler if we actually modify it
-    #[cfg(feature = "with-cheatcode")]
-    let previous_syscall_handler = syscall_handler.as_mut().map(|syscall_handler| {
-        let previous_syscall_handler = crate::starknet::SYSCALL_HANDLER_VTABLE.get();
-        let syscall_handler_ptr = std::ptr::addr_of!(*syscall_handler) as *mut ();
-        crate::starknet::SYSCALL_HANDLER_VTABLE.set(syscall_handler_ptr);
-
-        previous_syscall_handler
-    });
 
     // Generate argument list.
     let mut iter = args.iter();
@@ -226,47 +261,97 @@ fn invoke_dynamic(
             CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::System(_)) => {
                 let syscall_handler = syscall_handler
                     .as_mut()
-                    .expect("syscall handler is required");
+                    .ok_or(Error::MissingSyscallHandler)?;
 
                 invoke_data.push_aligned(
                     get_integer_layout(64).align(),
                     &[syscall_handler as *mut _ as u64],
                 );
             }
-            type_info => invoke_data
-                .push(
-                    type_id,
-                    type_info,
-                    if type_info.is_builtin() {
-                        &JitValue::Uint64(0)
-                    } else {
-                        iter.next().unwrap()
-                    },
-                )
-                .unwrap(),
+            type_info => invoke_data.push(
+                type_id,
+                type_info,
+                if type_info.is_builtin() {
+                    &JitValue::Uint64(0)
+                } else {
+                    iter.next()
+                        .ok_or_else(|| Error::make_missing_parameter(type_id))?
+                },
+            )?,
         }
     }
 
+    if iter.next().is_some() {
+        return Err(Error::TooManyArguments);
+    }
+
     // Invoke the trampoline.
     #[cfg(target_arch = "x86_64")]
     let mut ret_registers = [0; 2];
     #[cfg(target_arch = "aarch64")]
     let mut ret_registers = [0; 4];
 
-    unsafe {
-        invoke_trampoline(
-            function_ptr,
-            invoke_data.invoke_data().as_ptr(),
-            invoke_data.invoke_data().len(),
-            ret_registers.as_mut_ptr(),
-        );
-    }
+    // The syscall handler used by the cheatcode, the pending trap and the trace hook are all
+    // thread-local, so they must be handled on the thread that runs the program.
+    let trap_code = unsafe {
+        run_with_stack_size(stack_size, || {
+            // We only care for the previous syscall handler if we actually modify it
+            #[cfg(feature = "with-cheatcode")]
+            let previous_syscall_handler = syscall_handler.as_mut().map(|syscall_handler| {
+                let previous_syscall_handler = crate::starknet::SYSCALL_HANDLER_VTABLE.get();
+                let syscall_handler_ptr = std::ptr::addr_of!(*syscall_handler) as *mut ();
+                crate::starknet::SYSCALL_HANDLER_VTABLE.set(syscall_handler_ptr);
+
+                previous_syscall_handler
+            });
+
+            let trace_hook = trace_hook.as_ref().map(|(set_trace_hook, trace_hook)| {
+                let trace_hook_data = TraceHookData {
+                    trace_hook: *trace_hook,
+                    panic: Cell::new(None),
+                };
+                (*set_trace_hook, trace_hook_data)
+            });
+            if let Some((set_trace_hook, trace_hook_data)) = &trace_hook {
+                set_trace_hook(
+                    Some(trace_trampoline),
+                    trace_hook_data as *const TraceHookData as *mut c_void,
+                );
+            }
 
-    // If the syscall handler was changed, then reset the previous one.
-    // It's only necessary to restore the pointer if it's been modified i.e. if previous_syscall_handler is Some(...)
-    #[cfg(feature = "with-cheatcode")]
-    if let Some(previous_syscall_handler) = previous_syscall_handler {
-        crate::starknet::SYSCALL_HANDLER_VTABLE.set(previous_syscall_handler);
+            invoke_trampoline(
+                function_ptr,
+                invoke_data.invoke_data().as_ptr(),
+                invoke_data.invoke_data().len(),
+                ret_registers.as_mut_ptr(),
+            );
+
+            let trace_hook_panic = trace_hook.and_then(|(set_trace_hook, trace_hook_data)| {
+                set_trace_hook(None, null_mut());
+                trace_hook_data.panic.into_inner()
+            });
+
+            // If the syscall handler was changed, then reset the previous one.
+            // It's only necessary to restore the pointer if it's been modified i.e. if previous_syscall_handler is Some(...)
+            #[cfg(feature = "with-cheatcode")]
+            if let Some(previous_syscall_handler) = previous_syscall_handler {
+                crate::starknet::SYSCALL_HANDLER_VTABLE.set(previous_syscall_handler);
+            }
+
+            let trap_code = take_trap.map_or(0, |take_trap| take_trap());
+
+            // Panics can't unwind through the generated code, so the hook's panic is resumed here.
+            if let Some(payload) = trace_hook_panic {
+                std::panic::resume_unwind(payload);
+            }
+
+            trap_code
+        })?
+    };
+
+    // Check for traps before reading the return values, which may be poisoned.
+    if trap_code != 0 {
+        return Err(Error::make_trap(trap_code));
     }
 
     // Parse final gas.
@@ -326,30 +411,56 @@ fn invoke_dynamic(
     }
 
     // Parse return values.
-    let return_value = function_signature
+    let value_types = function_signature
         .ret_types
-        .last()
-        .and_then(|ret_type| {
-            let type_info = registry.get_type(ret_type).unwrap();
-            if type_info.is_builtin() {
-                None
-            } else {
-                Some(parse_result(
-                    ret_type,
-                    registry,
-                    return_ptr,
-                    ret_registers,
-                    // TODO: Consider returning an Option<JitValue> as return_value instead
-                    // As cairo functions can not have a return value
-                ))
-            }
-        })
-        .unwrap_or_else(|| {
-            Ok(JitValue::Struct {
-                fields: vec![],
+        .iter()
+        .filter(|id| !registry.get_type(id).unwrap().is_builtin())
+        .collect::<Vec<_>>();
+    let is_panic_result = match value_types.as_slice() {
+        [ret_type] => is_panic_result(registry, ret_type)?,
+        _ => false,
+    };
+    let return_value = match value_types.as_slice() {
+        // TODO: Consider returning an Option<JitValue> as return_value instead
+        // As cairo functions can not have a return value
+        [] => JitValue::Struct {
+            fields: vec![],
+            debug_name: None,
+        },
+        [ret_type] => parse_result(ret_type, registry, return_ptr, ret_registers)?,
+        // Multiple return values are always returned through the return pointer, one after the
+        // other. They're returned as a struct with all of them.
+        ret_types => {
+            let mut return_ptr = return_ptr.ok_or(Error::MissingReturnPointer)?;
+            let fields = ret_types
+                .iter()
+                .map(|ret_type| {
+                    let layout = registry
+                        .get_type(ret_type)
+                        .unwrap()
+                        .layout(registry)
+                        .unwrap();
+
+                    unsafe {
+                        let value_ptr = return_ptr.cast::<u8>().as_ptr();
+                        let value_ptr = value_ptr.add(value_ptr.align_offset(layout.align()));
+
+                        return_ptr = NonNull::new_unchecked(value_ptr.add(layout.size())).cast();
+                        JitValue::from_jit(
+                            NonNull::new_unchecked(value_ptr).cast(),
+                            ret_type,
+                            registry,
+                        )
+                    }
+                })
+                .collect();
+
+            JitValue::Struct {
+                fields,
                 debug_name: None,
-            })
-        })?;
+            }
+        }
+    };
 
     // FIXME: Arena deallocation.
     std::mem::forget(arena);
@@ -358,6 +469,119 @@ fn invoke_dynamic(
         remaining_gas,
         return_value,
         builtin_stats,
+        is_panic_result,
+    })
+}
+
+/// Return whether a type is a `core::panics::PanicResult`.
+///
+/// Without debug names, panic results are recognized by their `Err` variant: a `Panic` unit struct
+/// followed by the panic data.
+fn is_panic_result(
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    type_id: &ConcreteTypeId,
+) -> Result<bool, Error> {
+    let CoreTypeConcrete::Enum(info) = registry.get_type(type_id)? else {
+        return Ok(false);
+    };
+    if let Some(GenericArg::UserType(user_type_id)) = info.info.long_id.generic_args.first() {
+        if let Some(debug_name) = &user_type_id.debug_name {
+            return Ok(debug_name.starts_with("core::panics::PanicResult::"));
+        }
+    }
+
+    let [_, err_type] = info.variants.as_slice() else {
+        return Ok(false);
+    };
+    let CoreTypeConcrete::Struct(err_info) = registry.get_type(err_type)? else {
+        return Ok(false);
+    };
+    let [panic_type, data_type] = err_info.members.as_slice() else {
+        return Ok(false);
+    };
+
+    let is_panic = match registry.get_type(panic_type)? {
+        CoreTypeConcrete::Struct(panic_info) => panic_info.members.is_empty(),
+        _ => false,
+    };
+    Ok(is_panic && matches!(registry.get_type(data_type)?, CoreTypeConcrete::Array(_)))
+}
+
+/// The data passed to [`trace_trampoline`].
+struct TraceHookData<'a> {
+    trace_hook: &'a dyn Fn(&TraceEvent),
+    /// The payload of the panic raised by the trace hook, if any.
+    panic: Cell<Option<Box<dyn Any + Send>>>,
+}
+
+/// Forward a statement trace event from the runtime to the hook pointed to by `data`, which is a
+/// [`TraceHookData`].
+///
+/// Once the hook panics, its panic is kept to be resumed after the invocation and the remaining
+/// events are dropped.
+unsafe extern "C" fn trace_trampoline(
+    data: *mut c_void,
+    statement_idx: u64,
+    var_id: u64,
+    type_id: u64,
+    value_ptr: *const u8,
+    value_size: u64,
+) {
+    let trace_hook_data = &*data.cast::<TraceHookData>();
+
+    let panic = trace_hook_data.panic.take();
+    if panic.is_some() {
+        trace_hook_data.panic.set(panic);
+        return;
+    }
+
+    let event = TraceEvent {
+        statement_idx: StatementIdx(statement_idx as usize),
+        var_id: VarId::new(var_id),
+        type_id: ConcreteTypeId::new(type_id),
+        value: std::slice::from_raw_parts(value_ptr, value_size as usize),
+    };
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| (trace_hook_data.trace_hook)(&event))) {
+        trace_hook_data.panic.set(Some(payload));
+    }
+}
+
+/// Run `f` on a dedicated thread with a stack of `stack_size` bytes, or on the current thread if
+/// no stack size is given. The current thread is blocked until `f` returns.
+///
+/// # Safety
+///
+/// The closure is moved to the other thread even if it isn't `Send`. The caller must ensure that
+/// it doesn't rely on the thread it's run on, other than for the state it handles itself.
+unsafe fn run_with_stack_size<T>(
+    stack_size: Option<usize>,
+    f: impl FnOnce() -> T,
+) -> Result<T, Error> {
+    struct AssertSend<T>(T);
+    unsafe impl<T> Send for AssertSend<T> {}
+
+    impl<T> AssertSend<T> {
+        fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    let Some(stack_size) = stack_size else {
+        return Ok(f());
+    };
+
+    let f = AssertSend(f);
+    std::thread::scope(|scope| {
+        let handle = std::thread::Builder::new()
+            .name("cairo-native-invoke".to_string())
+            .stack_size(stack_size)
+            .spawn_scoped(scope, move || AssertSend(f.into_inner()()))
+            .map_err(Error::SpawnThread)?;
+
+        match handle.join() {
+            Ok(result) => Ok(result.into_inner()),
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
     })
 }
 
@@ -425,7 +649,7 @@ impl<'a> ArgumentMapper<'a> {
         type_id: &ConcreteTypeId,
         type_info: &CoreTypeConcrete,
         value: &JitValue,
-    ) -> Result<(), Box<ProgramRegistryError>> {
+    ) -> Result<(), Error> {
         match (type_info, value) {
             (CoreTypeConcrete::Array(info), JitValue::Array(values)) => {
                 // TODO: Assert that `info.ty` matches all the values' types.
@@ -472,6 +696,10 @@ impl<'a> ArgumentMapper<'a> {
                 self.push_aligned(align, &d.to_le_digits());
             }
             (CoreTypeConcrete::Enum(info), JitValue::Enum { tag, value, .. }) => {
+                if *tag >= info.variants.len() {
+                    return Err(Error::make_unexpected_value(type_id));
+                }
+
                 if type_info.is_memory_allocated(self.registry) {
                     let (layout, tag_layout, variant_layouts) =
                         crate::types::r#enum::get_layout_for_variants(
@@ -549,6 +777,10 @@ impl<'a> ArgumentMapper<'a> {
                 );
             }
             (CoreTypeConcrete::Struct(info), JitValue::Struct { fields, .. }) => {
+                if fields.len() != info.members.len() {
+                    return Err(Error::make_unexpected_value(type_id));
+                }
+
                 for (field_type_id, field_value) in info.members.iter().zip(fields) {
                     self.push(
                         field_type_id,
@@ -619,7 +851,7 @@ impl<'a> ArgumentMapper<'a> {
             | (CoreTypeConcrete::SegmentArena(_), JitValue::Uint64(value)) => {
                 self.push_aligned(get_integer_layout(64).align(), &[*value])
             }
-            (_, _) => todo!(),
+            (_, _) => return Err(Error::make_unexpected_value(type_id)),
         }
 
         Ok(())
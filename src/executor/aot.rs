@@ -1,6 +1,7 @@
 use crate::{
     error::Error,
     execution_result::{ContractExecutionResult, ExecutionResult},
+    executor::TraceEvent,
     metadata::gas::GasMetadata,
     module::NativeModule,
     starknet::{DummySyscallHandler, StarknetSyscallHandler},
@@ -29,6 +30,9 @@ pub struct AotNativeExecutor {
     registry: ProgramRegistry<CoreType, CoreLibfunc>,
 
     gas_metadata: GasMetadata,
+    stack_size: Option<usize>,
+    #[educe(Debug(ignore))]
+    trace_hook: Option<Box<dyn Fn(&TraceEvent) + Send + Sync>>,
 }
 
 impl AotNativeExecutor {
@@ -41,6 +45,8 @@ impl AotNativeExecutor {
             library,
             registry,
             gas_metadata,
+            stack_size: None,
+            trace_hook: None,
         }
     }
 
@@ -61,9 +67,34 @@ impl AotNativeExecutor {
             library: unsafe { Library::new(library_path).unwrap() },
             registry,
             gas_metadata: metadata.remove().unwrap(),
+            stack_size: None,
+            trace_hook: None,
         }
     }
 
+    /// Run every invocation on a dedicated thread with a stack of `stack_size` bytes.
+    ///
+    /// Native code uses the OS stack for recursion, so deeply recursive programs may need a larger
+    /// stack than the calling thread's. Direct tail-recursive calls are turned into loops by the
+    /// compiler and don't grow the stack.
+    pub fn with_stack_size(mut self, stack_size: usize) -> Self {
+        self.stack_size = Some(stack_size);
+        self
+    }
+
+    /// Report the statement trace events of every invocation to `trace_hook`.
+    ///
+    /// The program must have been compiled with
+    /// [`NativeContext::with_trace_statements`](crate::context::NativeContext::with_trace_statements),
+    /// otherwise no events are reported.
+    pub fn with_trace_hook(
+        mut self,
+        trace_hook: impl Fn(&TraceEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.trace_hook = Some(Box::new(trace_hook));
+        self
+    }
+
     pub fn invoke_dynamic(
         &self,
         function_id: &FunctionId,
@@ -82,6 +113,9 @@ impl AotNativeExecutor {
             args,
             available_gas,
             Option::<DummySyscallHandler>::None,
+            self.find_take_trap()?,
+            self.stack_size,
+            self.find_trace_hook(),
         )
     }
 
@@ -104,6 +138,9 @@ impl AotNativeExecutor {
             args,
             available_gas,
             Some(syscall_handler),
+            self.find_take_trap()?,
+            self.stack_size,
+            self.find_trace_hook(),
         )
     }
 
@@ -132,9 +169,54 @@ impl AotNativeExecutor {
             }],
             available_gas,
             Some(syscall_handler),
+            self.find_take_trap()?,
+            self.stack_size,
+            self.find_trace_hook(),
         )?)
     }
 
+    /// Return the runtime's trap accessor linked into the shared library.
+    ///
+    /// Libraries which don't reference the trap runtime can't trap, so they don't need it. Otherwise
+    /// a missing accessor is an error, since traps would go unnoticed.
+    fn find_take_trap(&self) -> Result<Option<super::TakeTrapFn>, Error> {
+        unsafe {
+            match self
+                .library
+                .get::<super::TakeTrapFn>(b"cairo_native__take_trap")
+            {
+                Ok(take_trap) => Ok(Some(*take_trap)),
+                Err(_)
+                    if self
+                        .library
+                        .get::<*const ()>(b"cairo_native__trap")
+                        .is_err()
+                        && self
+                            .library
+                            .get::<*const ()>(b"cairo_native__peek_trap")
+                            .is_err() =>
+                {
+                    Ok(None)
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+
+    /// Return the runtime's trace hook installer linked into the shared library, along with the
+    /// trace hook, if both exist.
+    fn find_trace_hook(&self) -> Option<(super::SetTraceHookFn, &dyn Fn(&TraceEvent))> {
+        let trace_hook: &dyn Fn(&TraceEvent) = self.trace_hook.as_deref()?;
+        let set_trace_hook = unsafe {
+            self.library
+                .get::<super::SetTraceHookFn>(b"cairo_native__set_trace_hook")
+                .ok()
+                .map(|x| *x)
+        }?;
+
+        Some((set_trace_hook, trace_hook))
+    }
+
     pub fn find_function_ptr(&self, function_id: &FunctionId) -> *mut c_void {
         let function_name = generate_function_name(function_id);
         let function_name = format!("_mlir_ciface_{function_name}");
@@ -159,11 +241,13 @@ mod tests {
     use super::*;
     use crate::{
         context::NativeContext,
+        executor::JitNativeExecutor,
         starknet_stub::StubSyscallHandler,
-        utils::test::{load_cairo, load_starknet},
+        utils::test::{jit_enum, jit_struct, load_cairo, load_starknet},
     };
     use cairo_lang_sierra::program::Program;
     use rstest::*;
+    use std::sync::{Arc, Mutex};
 
     #[fixture]
     fn program() -> Program {
@@ -285,4 +369,151 @@ mod tests {
 
         assert_eq!(result.return_values, vec![Felt::from(42)]);
     }
+
+    #[test]
+    fn test_shared_library_matches_jit() {
+        let (_, program) = load_cairo! {
+            fn fib(a: felt252, b: felt252, n: felt252) -> felt252 {
+                match n {
+                    0 => a,
+                    _ => fib(b, a + b, n - 1),
+                }
+            }
+
+            fn run_test(n: felt252) -> felt252 {
+                fib(0, 1, n)
+            }
+        };
+        let entrypoint_function_id = &program
+            .funcs
+            .iter()
+            .find(|f| {
+                f.id.debug_name
+                    .as_deref()
+                    .is_some_and(|name| name.ends_with("::run_test"))
+            })
+            .expect("should have a function")
+            .id;
+        let args = [JitValue::Felt252(Felt::from(20))];
+
+        let native_context = NativeContext::new();
+        let jit_result = JitNativeExecutor::from_native_module(
+            native_context.compile(&program, None).unwrap(),
+            OptLevel::default(),
+        )
+        .invoke_dynamic(entrypoint_function_id, &args, Some(u128::MAX))
+        .unwrap();
+
+        // Store the shared library at a known path, then load it back as if it had been cached by
+        // a previous process.
+        let NativeModule {
+            module,
+            registry,
+            mut metadata,
+        } = native_context.compile(&program, None).unwrap();
+        let library_path = tempfile::Builder::new()
+            .prefix("lib")
+            .suffix(crate::utils::SHARED_LIBRARY_EXT)
+            .tempfile()
+            .unwrap()
+            .into_temp_path();
+        let object_data = crate::module_to_object(&module, OptLevel::default()).unwrap();
+        crate::object_to_shared_lib(&object_data, &library_path).unwrap();
+
+        let executor = AotNativeExecutor::new(
+            unsafe { Library::new(&library_path).unwrap() },
+            registry,
+            metadata.remove().unwrap(),
+        );
+        let aot_result = executor
+            .invoke_dynamic(entrypoint_function_id, &args, Some(u128::MAX))
+            .unwrap();
+
+        assert_eq!(
+            aot_result.return_value,
+            jit_enum!(0, jit_struct!(JitValue::Felt252(Felt::from(6765))))
+        );
+        assert_eq!(aot_result, jit_result);
+    }
+
+    #[test]
+    fn test_invoke_from_multiple_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AotNativeExecutor>();
+
+        let (_, program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252) -> felt252 {
+                a * b
+            }
+        };
+        let entrypoint_function_id = &program.funcs.first().expect("should have a function").id;
+
+        let native_context = NativeContext::new();
+        let module = native_context.compile(&program, None).unwrap();
+        let executor = AotNativeExecutor::from_native_module(module, OptLevel::default());
+
+        std::thread::scope(|scope| {
+            let handles = (0..8u64)
+                .map(|i| {
+                    let executor = &executor;
+                    scope.spawn(move || {
+                        executor
+                            .invoke_dynamic(
+                                entrypoint_function_id,
+                                &[
+                                    JitValue::Felt252(Felt::from(i)),
+                                    JitValue::Felt252(Felt::from(i + 1)),
+                                ],
+                                Some(u128::MAX),
+                            )
+                            .unwrap()
+                            .return_value
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for (i, handle) in (0..8u64).zip(handles) {
+                assert_eq!(
+                    handle.join().unwrap(),
+                    JitValue::Felt252(Felt::from(i * (i + 1)))
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_trace_statements() {
+        let (_, program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252) -> felt252 {
+                a * b
+            }
+        };
+        let entrypoint_function_id = &program.funcs.first().expect("should have a function").id;
+
+        let native_context = NativeContext::new().with_trace_statements(true);
+        let module = native_context.compile(&program, None).unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let executor = AotNativeExecutor::from_native_module(module, OptLevel::default())
+            .with_trace_hook({
+                let events = events.clone();
+                move |event| events.lock().unwrap().push(event.value.to_vec())
+            });
+
+        let result = executor
+            .invoke_dynamic(
+                entrypoint_function_id,
+                &[
+                    JitValue::Felt252(Felt::from(2)),
+                    JitValue::Felt252(Felt::from(3)),
+                ],
+                Some(u128::MAX),
+            )
+            .unwrap();
+        assert_eq!(result.return_value, JitValue::Felt252(Felt::from(6)));
+
+        // The trace hook installer has been linked into the shared library.
+        let events = events.lock().unwrap();
+        assert!(events.contains(&Felt::from(6).to_bytes_le().to_vec()));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{
     error::Error,
     execution_result::{ContractExecutionResult, ExecutionResult},
+    executor::TraceEvent,
     metadata::gas::GasMetadata,
     module::NativeModule,
     starknet::{DummySyscallHandler, StarknetSyscallHandler},
@@ -26,6 +27,8 @@ pub struct JitNativeExecutor<'m> {
     registry: ProgramRegistry<CoreType, CoreLibfunc>,
 
     gas_metadata: GasMetadata,
+    stack_size: Option<usize>,
+    trace_hook: Option<Box<dyn Fn(&TraceEvent) + Send + Sync>>,
 }
 
 impl std::fmt::Debug for JitNativeExecutor<'_> {
@@ -33,6 +36,7 @@ impl std::fmt::Debug for JitNativeExecutor<'_> {
         f.debug_struct("JitNativeExecutor")
             .field("module", &self.module)
             .field("gas_metadata", &self.gas_metadata)
+            .field("stack_size", &self.stack_size)
             .finish()
     }
 }
@@ -50,9 +54,34 @@ impl<'m> JitNativeExecutor<'m> {
             module,
             registry,
             gas_metadata: metadata.get::<GasMetadata>().cloned().unwrap(),
+            stack_size: None,
+            trace_hook: None,
         }
     }
 
+    /// Run every invocation on a dedicated thread with a stack of `stack_size` bytes.
+    ///
+    /// Native code uses the OS stack for recursion, so deeply recursive programs may need a larger
+    /// stack than the calling thread's. Direct tail-recursive calls are turned into loops by the
+    /// compiler and don't grow the stack.
+    pub fn with_stack_size(mut self, stack_size: usize) -> Self {
+        self.stack_size = Some(stack_size);
+        self
+    }
+
+    /// Report the statement trace events of every invocation to `trace_hook`.
+    ///
+    /// The program must have been compiled with
+    /// [`NativeContext::with_trace_statements`](crate::context::NativeContext::with_trace_statements),
+    /// otherwise no events are reported.
+    pub fn with_trace_hook(
+        mut self,
+        trace_hook: impl Fn(&TraceEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.trace_hook = Some(Box::new(trace_hook));
+        self
+    }
+
     pub fn program_registry(&self) -> &ProgramRegistry<CoreType, CoreLibfunc> {
         &self.registry
     }
@@ -82,6 +111,9 @@ impl<'m> JitNativeExecutor<'m> {
             args,
             available_gas,
             Option::<DummySyscallHandler>::None,
+            self.find_take_trap(),
+            self.stack_size,
+            self.find_trace_hook(),
         )
     }
 
@@ -107,6 +139,9 @@ impl<'m> JitNativeExecutor<'m> {
             args,
             available_gas,
             Some(syscall_handler),
+            self.find_take_trap(),
+            self.stack_size,
+            self.find_trace_hook(),
         )
     }
 
@@ -135,9 +170,41 @@ impl<'m> JitNativeExecutor<'m> {
             }],
             available_gas,
             Some(syscall_handler),
+            self.find_take_trap(),
+            self.stack_size,
+            self.find_trace_hook(),
         )?)
     }
 
+    /// Return the runtime's trap accessor.
+    #[cfg(feature = "with-runtime")]
+    fn find_take_trap(&self) -> Option<super::TakeTrapFn> {
+        Some(cairo_native_runtime::cairo_native__take_trap)
+    }
+
+    /// Without the runtime there are no traps to report.
+    #[cfg(not(feature = "with-runtime"))]
+    fn find_take_trap(&self) -> Option<super::TakeTrapFn> {
+        None
+    }
+
+    /// Return the runtime's trace hook installer along with the trace hook, if there's one.
+    #[cfg(feature = "with-runtime")]
+    fn find_trace_hook(&self) -> Option<(super::SetTraceHookFn, &dyn Fn(&TraceEvent))> {
+        let trace_hook: &dyn Fn(&TraceEvent) = self.trace_hook.as_deref()?;
+
+        Some((
+            cairo_native_runtime::cairo_native__set_trace_hook,
+            trace_hook,
+        ))
+    }
+
+    /// Without the runtime there are no trace events to report.
+    #[cfg(not(feature = "with-runtime"))]
+    fn find_trace_hook(&self) -> Option<(super::SetTraceHookFn, &dyn Fn(&TraceEvent))> {
+        None
+    }
+
     pub fn find_function_ptr(&self, function_id: &FunctionId) -> *mut c_void {
         let function_name = generate_function_name(function_id);
         let function_name = format!("_mlir_ciface_{function_name}");
@@ -154,3 +221,460 @@ impl<'m> JitNativeExecutor<'m> {
             .signature
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "with-runtime")]
+    use crate::error::TrapCode;
+    use crate::{
+        context::NativeContext,
+        utils::{
+            find_function_id,
+            test::{jit_enum, jit_struct, load_cairo},
+        },
+    };
+    use cairo_lang_sierra::{program::Program, ProgramParser};
+    use rstest::*;
+    #[cfg(feature = "with-runtime")]
+    use std::sync::{Arc, Mutex};
+
+    #[fixture]
+    fn program() -> (String, Program) {
+        load_cairo! {
+            #[derive(Drop)]
+            struct Point {
+                x: u32,
+                y: u32,
+            }
+
+            fn fib(a: felt252, b: felt252, n: felt252) -> felt252 {
+                match n {
+                    0 => a,
+                    _ => fib(b, a + b, n - 1),
+                }
+            }
+
+            fn run_fib(n: felt252) -> felt252 {
+                fib(0, 1, n)
+            }
+
+            fn split(n: u32) -> Result<(u32, Array<felt252>), felt252> {
+                if n % 2 == 0 {
+                    Result::Ok((n / 2, array![1, 2]))
+                } else {
+                    Result::Err(42)
+                }
+            }
+
+            fn checked_split(n: u32) -> (u32, Array<felt252>) {
+                match split(n) {
+                    Result::Ok(x) => x,
+                    Result::Err(e) => panic_with_felt252(e),
+                }
+            }
+
+            fn classify(p: Point) -> Option<u32> {
+                if p.x == p.y {
+                    Option::None
+                } else {
+                    Option::Some(p.x)
+                }
+            }
+        }
+    }
+
+    fn invoke(
+        program: &(String, Program),
+        name: &str,
+        args: &[JitValue],
+    ) -> Result<JitValue, Error> {
+        let function_id = find_function_id(&program.1, &format!("{0}::{0}::{name}", program.0));
+
+        let native_context = NativeContext::new();
+        let module = native_context.compile(&program.1, None)?;
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+        Ok(executor
+            .invoke_dynamic(function_id, args, Some(u128::MAX))?
+            .return_value)
+    }
+
+    #[rstest]
+    fn test_invoke_fib(program: (String, Program)) {
+        let result = invoke(&program, "run_fib", &[JitValue::Felt252(Felt::from(10))]).unwrap();
+        assert_eq!(
+            result,
+            jit_enum!(0, jit_struct!(JitValue::Felt252(Felt::from(55))))
+        );
+    }
+
+    #[rstest]
+    fn test_invoke_struct_to_enum(program: (String, Program)) {
+        let result = invoke(
+            &program,
+            "classify",
+            &[jit_struct!(3u32.into(), 1u32.into())],
+        )
+        .unwrap();
+        assert_eq!(result, jit_enum!(0, 3u32.into()));
+
+        let result = invoke(
+            &program,
+            "classify",
+            &[jit_struct!(2u32.into(), 2u32.into())],
+        )
+        .unwrap();
+        assert_eq!(result, jit_enum!(1, jit_struct!()));
+    }
+
+    #[rstest]
+    fn test_invoke_argument_mismatch(program: (String, Program)) {
+        assert!(matches!(
+            invoke(&program, "run_fib", &[]),
+            Err(Error::MissingParameter(_))
+        ));
+        assert!(matches!(
+            invoke(
+                &program,
+                "run_fib",
+                &[
+                    JitValue::Felt252(Felt::from(1)),
+                    JitValue::Felt252(Felt::from(2))
+                ]
+            ),
+            Err(Error::TooManyArguments)
+        ));
+        assert!(matches!(
+            invoke(&program, "run_fib", &[JitValue::Uint8(10)]),
+            Err(Error::UnexpectedValue(_))
+        ));
+        assert!(matches!(
+            invoke(&program, "classify", &[jit_struct!(3u32.into())]),
+            Err(Error::UnexpectedValue(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_invoke_nested_result(program: (String, Program)) {
+        let result = invoke(&program, "split", &[4u32.into()]).unwrap();
+        assert_eq!(
+            result,
+            jit_enum!(
+                0,
+                jit_struct!(
+                    2u32.into(),
+                    JitValue::Array(vec![
+                        JitValue::Felt252(Felt::from(1)),
+                        JitValue::Felt252(Felt::from(2)),
+                    ]),
+                )
+            )
+        );
+
+        let result = invoke(&program, "split", &[3u32.into()]).unwrap();
+        assert_eq!(result, jit_enum!(1, JitValue::Felt252(Felt::from(42))));
+    }
+
+    #[rstest]
+    fn test_invoke_panic_result(program: (String, Program)) {
+        let function_id =
+            find_function_id(&program.1, &format!("{0}::{0}::checked_split", program.0));
+
+        let native_context = NativeContext::new();
+        let module = native_context.compile(&program.1, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+        let result = executor
+            .invoke_dynamic(function_id, &[4u32.into()], Some(u128::MAX))
+            .unwrap();
+        assert_eq!(
+            result.panic_result(),
+            Some(Ok(&[jit_struct!(
+                2u32.into(),
+                JitValue::Array(vec![
+                    JitValue::Felt252(Felt::from(1)),
+                    JitValue::Felt252(Felt::from(2)),
+                ]),
+            )][..]))
+        );
+
+        let result = executor
+            .invoke_dynamic(function_id, &[3u32.into()], Some(u128::MAX))
+            .unwrap();
+        assert_eq!(
+            result.panic_result(),
+            Some(Err(&[JitValue::Felt252(Felt::from(42))][..]))
+        );
+    }
+
+    #[test]
+    fn test_invoke_hides_builtins() {
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type RangeCheck = RangeCheck;
+                    type GasBuiltin = GasBuiltin;
+                    type felt252 = felt252;
+
+                    libfunc felt252_add = felt252_add;
+                    libfunc store_temp<RangeCheck> = store_temp<RangeCheck>;
+                    libfunc store_temp<GasBuiltin> = store_temp<GasBuiltin>;
+                    libfunc store_temp<felt252> = store_temp<felt252>;
+
+                    felt252_add([2], [3]) -> ([4]);
+                    store_temp<RangeCheck>([0]) -> ([0]);
+                    store_temp<GasBuiltin>([1]) -> ([1]);
+                    store_temp<felt252>([4]) -> ([4]);
+                    return([0], [1], [4]);
+
+                    run_test@0([0]: RangeCheck, [1]: GasBuiltin, [2]: felt252, [3]: felt252) -> (RangeCheck, GasBuiltin, felt252);
+                "#,
+            )
+            .unwrap();
+        let function_id = find_function_id(&program, "run_test");
+
+        let native_context = NativeContext::new();
+        let module = native_context.compile(&program, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+        // Only the felts are passed and returned, the builtins are handled by the executor.
+        let result = executor
+            .invoke_dynamic(
+                function_id,
+                &[
+                    JitValue::Felt252(Felt::from(2)),
+                    JitValue::Felt252(Felt::from(3)),
+                ],
+                Some(1000),
+            )
+            .unwrap();
+        assert_eq!(result.return_value, JitValue::Felt252(Felt::from(5)));
+        assert!(result.remaining_gas.is_some());
+
+        assert!(matches!(
+            executor.invoke_dynamic(
+                function_id,
+                &[
+                    JitValue::Felt252(Felt::from(2)),
+                    JitValue::Felt252(Felt::from(3)),
+                    JitValue::Felt252(Felt::from(4)),
+                ],
+                Some(1000),
+            ),
+            Err(Error::TooManyArguments)
+        ));
+    }
+
+    #[cfg(feature = "with-runtime")]
+    #[test]
+    fn test_invalid_enum_tag_traps() {
+        // Sierra doesn't type-check variables, which allows passing a `Tag4::D` (tag 3) where a
+        // `Tag3` is expected. Both are represented by a 2-bit tag.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type Unit = Struct<ut@Tuple>;
+                    type Tag3 = Enum<ut@Tag3, Unit, Unit, Unit>;
+                    type Tag4 = Enum<ut@Tag4, Unit, Unit, Unit, Unit>;
+                    type u8 = u8;
+
+                    libfunc struct_construct<Unit> = struct_construct<Unit>;
+                    libfunc enum_init<Tag4, 3> = enum_init<Tag4, 3>;
+                    libfunc enum_match<Tag3> = enum_match<Tag3>;
+                    libfunc drop<Unit> = drop<Unit>;
+                    libfunc u8_const<1> = u8_const<1>;
+                    libfunc u8_const<2> = u8_const<2>;
+                    libfunc u8_const<3> = u8_const<3>;
+                    libfunc store_temp<u8> = store_temp<u8>;
+                    libfunc store_temp<Tag4> = store_temp<Tag4>;
+                    libfunc function_call<user@program::program::classify> = function_call<user@program::program::classify>;
+
+                    enum_match<Tag3>([0]) { fallthrough([1]) 5([1]) 9([1]) };
+                    drop<Unit>([1]) -> ();
+                    u8_const<1>() -> ([2]);
+                    store_temp<u8>([2]) -> ([2]);
+                    return([2]);
+                    drop<Unit>([1]) -> ();
+                    u8_const<2>() -> ([2]);
+                    store_temp<u8>([2]) -> ([2]);
+                    return([2]);
+                    drop<Unit>([1]) -> ();
+                    u8_const<3>() -> ([2]);
+                    store_temp<u8>([2]) -> ([2]);
+                    return([2]);
+                    struct_construct<Unit>() -> ([0]);
+                    enum_init<Tag4, 3>([0]) -> ([1]);
+                    store_temp<Tag4>([1]) -> ([1]);
+                    function_call<user@program::program::classify>([1]) -> ([2]);
+                    return([2]);
+
+                    program::program::classify@0([0]: Tag3) -> (u8);
+                    program::program::run_test@13() -> (u8);
+                "#,
+            )
+            .unwrap();
+
+        let native_context = NativeContext::new();
+        let module = native_context.compile(&program, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+        let result = executor
+            .invoke_dynamic(
+                find_function_id(&program, "program::program::classify"),
+                &[jit_enum!(1, jit_struct!())],
+                None,
+            )
+            .unwrap();
+        assert_eq!(result.return_value, JitValue::Uint8(2));
+
+        // The process survives the invalid tag, which is reported as a trap instead. The trap is
+        // raised by the callee, which returns straight away, and so does its caller.
+        let error = executor
+            .invoke_dynamic(
+                find_function_id(&program, "program::program::run_test"),
+                &[],
+                None,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Trap { code, message }
+                if code == TrapCode::InvalidEnumTag as u64 && message == "invalid enum tag"
+        ));
+
+        // The trap is cleared once reported.
+        let result = executor
+            .invoke_dynamic(
+                find_function_id(&program, "program::program::classify"),
+                &[jit_enum!(0, jit_struct!())],
+                None,
+            )
+            .unwrap();
+        assert_eq!(result.return_value, JitValue::Uint8(1));
+    }
+
+    #[cfg(feature = "with-runtime")]
+    #[test]
+    fn test_trace_statements() {
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+                    type NonZeroFelt252 = NonZero<felt252>;
+
+                    libfunc dup_felt252 = dup<felt252>;
+                    libfunc felt252_add = felt252_add;
+                    libfunc felt252_mul = felt252_mul;
+                    libfunc felt252_is_zero = felt252_is_zero;
+                    libfunc branch_align = branch_align;
+                    libfunc unwrap_non_zero = unwrap_non_zero<felt252>;
+
+                    dup_felt252([0]) -> ([0], [1]);
+                    felt252_mul([0], [1]) -> ([2]);
+                    dup_felt252([2]) -> ([2], [3]);
+                    felt252_is_zero([3]) { fallthrough() 6([4]) };
+                    branch_align() -> ();
+                    return([2]);
+                    branch_align() -> ();
+                    unwrap_non_zero([4]) -> ([5]);
+                    felt252_add([2], [5]) -> ([6]);
+                    return([6]);
+
+                    program::program::run_test@0([0]: felt252) -> (felt252);
+                "#,
+            )
+            .unwrap();
+        let function_id = &program.funcs[0].id;
+
+        let run = |trace_statements: bool, arg: u8| {
+            let native_context = NativeContext::new().with_trace_statements(trace_statements);
+            let module = native_context.compile(&program, None).unwrap();
+
+            let events = Arc::new(Mutex::new(Vec::new()));
+            let executor = JitNativeExecutor::from_native_module(module, OptLevel::None)
+                .with_trace_hook({
+                    let events = events.clone();
+                    move |event| {
+                        events.lock().unwrap().push((
+                            event.statement_idx.0,
+                            event.var_id.id,
+                            event.type_id.id,
+                            event.value.to_vec(),
+                        ))
+                    }
+                });
+
+            let result = executor
+                .invoke_dynamic(function_id, &[JitValue::Felt252(arg.into())], None)
+                .unwrap();
+            assert_eq!(
+                result.return_value,
+                JitValue::Felt252((2 * arg * arg).into())
+            );
+
+            let events = events.lock().unwrap().clone();
+            events
+        };
+
+        // Named ids are hashed, so the type ids are taken from the declarations.
+        let (felt252_ty, non_zero_ty) = (
+            program.type_declarations[0].id.id,
+            program.type_declarations[1].id.id,
+        );
+        let felt = |value: u8| Felt::from(value).to_bytes_le().to_vec();
+        assert_eq!(
+            run(true, 3),
+            [
+                (0, 0, felt252_ty, felt(3)),
+                (0, 1, felt252_ty, felt(3)),
+                (1, 2, felt252_ty, felt(9)),
+                (2, 2, felt252_ty, felt(9)),
+                (2, 3, felt252_ty, felt(9)),
+                (3, 4, non_zero_ty, felt(9)),
+                (7, 5, felt252_ty, felt(9)),
+                (8, 6, felt252_ty, felt(18)),
+            ]
+        );
+        assert_eq!(
+            run(true, 0),
+            [
+                (0, 0, felt252_ty, felt(0)),
+                (0, 1, felt252_ty, felt(0)),
+                (1, 2, felt252_ty, felt(0)),
+                (2, 2, felt252_ty, felt(0)),
+                (2, 3, felt252_ty, felt(0)),
+            ]
+        );
+
+        assert!(run(false, 3).is_empty());
+        assert!(run(false, 0).is_empty());
+    }
+
+    #[cfg(feature = "with-runtime")]
+    #[test]
+    #[should_panic(expected = "trace hook panicked")]
+    fn test_trace_hook_panic() {
+        let (_, program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252) -> felt252 {
+                a * b
+            }
+        };
+        let function_id = &program.funcs[0].id;
+
+        let native_context = NativeContext::new().with_trace_statements(true);
+        let module = native_context.compile(&program, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None)
+            .with_trace_hook(|_| panic!("trace hook panicked"));
+
+        // The panic can't unwind through the program, but it's resumed once the invocation ends.
+        let _ = executor.invoke_dynamic(
+            function_id,
+            &[
+                JitValue::Felt252(Felt::from(2)),
+                JitValue::Felt252(Felt::from(3)),
+            ],
+            None,
+        );
+    }
+}
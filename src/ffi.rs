@@ -7,18 +7,20 @@ use crate::error::Error as CompileError;
 use llvm_sys::{
     core::{
         LLVMContextCreate, LLVMContextDispose, LLVMDisposeMemoryBuffer, LLVMDisposeMessage,
-        LLVMDisposeModule, LLVMGetBufferSize, LLVMGetBufferStart,
+        LLVMDisposeModule, LLVMGetBufferSize, LLVMGetBufferStart, LLVMPrintModuleToString,
+        LLVMSetTarget,
     },
     prelude::{LLVMContextRef, LLVMMemoryBufferRef, LLVMModuleRef},
     target::{
-        LLVM_InitializeAllAsmParsers, LLVM_InitializeAllAsmPrinters, LLVM_InitializeAllTargetInfos,
-        LLVM_InitializeAllTargetMCs, LLVM_InitializeAllTargets,
+        LLVMDisposeTargetData, LLVMSetModuleDataLayout, LLVM_InitializeAllAsmParsers,
+        LLVM_InitializeAllAsmPrinters, LLVM_InitializeAllTargetInfos, LLVM_InitializeAllTargetMCs,
+        LLVM_InitializeAllTargets,
     },
     target_machine::{
-        LLVMCodeGenFileType, LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetMachine,
-        LLVMDisposeTargetMachine, LLVMGetDefaultTargetTriple, LLVMGetHostCPUFeatures,
-        LLVMGetHostCPUName, LLVMGetTargetFromTriple, LLVMRelocMode,
-        LLVMTargetMachineEmitToMemoryBuffer, LLVMTargetRef,
+        LLVMCodeGenFileType, LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetDataLayout,
+        LLVMCreateTargetMachine, LLVMDisposeTargetMachine, LLVMGetDefaultTargetTriple,
+        LLVMGetHostCPUFeatures, LLVMGetHostCPUName, LLVMGetTargetFromTriple, LLVMRelocMode,
+        LLVMTargetMachineEmitToMemoryBuffer, LLVMTargetMachineRef, LLVMTargetRef,
     },
 };
 use melior::ir::{Module, Type, TypeLike};
@@ -116,9 +118,64 @@ pub fn module_to_object(
     module: &Module<'_>,
     opt_level: OptLevel,
 ) -> Result<Vec<u8>, LLVMCompileError> {
+    emit_module(module, opt_level, LLVMCodeGenFileType::LLVMObjectFile)
+}
+
+/// Converts a MLIR module to the host target's assembly code.
+pub fn module_to_assembly(
+    module: &Module<'_>,
+    opt_level: OptLevel,
+) -> Result<String, LLVMCompileError> {
+    let data = emit_module(module, opt_level, LLVMCodeGenFileType::LLVMAssemblyFile)?;
+    String::from_utf8(data).map_err(|e| LLVMCompileError(e.to_string()))
+}
+
+/// Converts a MLIR module, which must have been lowered into the LLVM dialect already, to LLVM IR.
+pub fn module_to_llvm_ir(module: &Module<'_>) -> Result<String, LLVMCompileError> {
+    unsafe {
+        let llvm_context = LLVMContextCreate();
+
+        let op = module.as_operation().to_raw();
+
+        let llvm_module = mlirTranslateModuleToLLVMIR(op, llvm_context);
+        if llvm_module.is_null() {
+            LLVMContextDispose(llvm_context);
+            return Err(LLVMCompileError(
+                "could not translate the module to LLVM IR".to_string(),
+            ));
+        }
+
+        // Print the IR for the host target, the same one `module_to_object` would compile for.
+        let machine = match create_host_target_machine(llvm_module, OptLevel::None) {
+            Ok(machine) => machine,
+            Err(e) => {
+                LLVMDisposeModule(llvm_module);
+                LLVMContextDispose(llvm_context);
+                return Err(e);
+            }
+        };
+
+        let ir_ptr = LLVMPrintModuleToString(llvm_module);
+        let ir = CStr::from_ptr(ir_ptr).to_string_lossy().into_owned();
+
+        LLVMDisposeMessage(ir_ptr);
+        LLVMDisposeTargetMachine(machine);
+        LLVMDisposeModule(llvm_module);
+        LLVMContextDispose(llvm_context);
+
+        Ok(ir)
+    }
+}
+
+/// Creates a target machine for the host and sets the module's target triple and data layout to
+/// match it.
+unsafe fn create_host_target_machine(
+    llvm_module: LLVMModuleRef,
+    opt_level: OptLevel,
+) -> Result<LLVMTargetMachineRef, LLVMCompileError> {
     static INITIALIZED: OnceLock<()> = OnceLock::new();
 
-    INITIALIZED.get_or_init(|| unsafe {
+    INITIALIZED.get_or_init(|| {
         LLVM_InitializeAllTargets();
         LLVM_InitializeAllTargetInfos();
         LLVM_InitializeAllTargetMCs();
@@ -126,6 +183,60 @@ pub fn module_to_object(
         LLVM_InitializeAllAsmParsers();
     });
 
+    let mut null = null_mut();
+    let error_buffer = addr_of_mut!(null);
+
+    let target_triple = LLVMGetDefaultTargetTriple();
+
+    let mut target: MaybeUninit<LLVMTargetRef> = MaybeUninit::uninit();
+
+    if LLVMGetTargetFromTriple(target_triple, target.as_mut_ptr(), error_buffer) != 0 {
+        let error = CStr::from_ptr(*error_buffer);
+        let err = error.to_string_lossy().to_string();
+        LLVMDisposeMessage(*error_buffer);
+        LLVMDisposeMessage(target_triple);
+        return Err(LLVMCompileError(err));
+    } else if !(*error_buffer).is_null() {
+        LLVMDisposeMessage(*error_buffer);
+    }
+
+    let target = target.assume_init();
+
+    let target_cpu = LLVMGetHostCPUName();
+    let target_cpu_features = LLVMGetHostCPUFeatures();
+
+    let machine = LLVMCreateTargetMachine(
+        target,
+        target_triple.cast(),
+        target_cpu.cast(),
+        target_cpu_features.cast(),
+        match opt_level {
+            OptLevel::None => LLVMCodeGenOptLevel::LLVMCodeGenLevelNone,
+            OptLevel::Less => LLVMCodeGenOptLevel::LLVMCodeGenLevelLess,
+            OptLevel::Default => LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault,
+            OptLevel::Aggressive => LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive,
+        },
+        LLVMRelocMode::LLVMRelocDynamicNoPic,
+        LLVMCodeModel::LLVMCodeModelDefault,
+    );
+
+    LLVMSetTarget(llvm_module, target_triple);
+    let data_layout = LLVMCreateTargetDataLayout(machine);
+    LLVMSetModuleDataLayout(llvm_module, data_layout);
+    LLVMDisposeTargetData(data_layout);
+
+    LLVMDisposeMessage(target_cpu_features);
+    LLVMDisposeMessage(target_cpu);
+    LLVMDisposeMessage(target_triple);
+
+    Ok(machine)
+}
+
+fn emit_module(
+    module: &Module<'_>,
+    opt_level: OptLevel,
+    file_type: LLVMCodeGenFileType,
+) -> Result<Vec<u8>, LLVMCompileError> {
     unsafe {
         let llvm_context = LLVMContextCreate();
 
@@ -134,47 +245,16 @@ pub fn module_to_object(
         let llvm_module = mlirTranslateModuleToLLVMIR(op, llvm_context);
 
         let mut null = null_mut();
-        let mut error_buffer = addr_of_mut!(null);
-
-        let target_triple = LLVMGetDefaultTargetTriple();
-        let target_cpu = LLVMGetHostCPUName();
-        let target_cpu_features = LLVMGetHostCPUFeatures();
-
-        let mut target: MaybeUninit<LLVMTargetRef> = MaybeUninit::uninit();
-
-        if LLVMGetTargetFromTriple(target_triple, target.as_mut_ptr(), error_buffer) != 0 {
-            let error = CStr::from_ptr(*error_buffer);
-            let err = error.to_string_lossy().to_string();
-            LLVMDisposeMessage(*error_buffer);
-            Err(LLVMCompileError(err))?;
-        } else if !(*error_buffer).is_null() {
-            LLVMDisposeMessage(*error_buffer);
-            error_buffer = addr_of_mut!(null);
-        }
+        let error_buffer = addr_of_mut!(null);
 
-        let target = target.assume_init();
-
-        let machine = LLVMCreateTargetMachine(
-            target,
-            target_triple.cast(),
-            target_cpu.cast(),
-            target_cpu_features.cast(),
-            match opt_level {
-                OptLevel::None => LLVMCodeGenOptLevel::LLVMCodeGenLevelNone,
-                OptLevel::Less => LLVMCodeGenOptLevel::LLVMCodeGenLevelLess,
-                OptLevel::Default => LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault,
-                OptLevel::Aggressive => LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive,
-            },
-            LLVMRelocMode::LLVMRelocDynamicNoPic,
-            LLVMCodeModel::LLVMCodeModelDefault,
-        );
+        let machine = create_host_target_machine(llvm_module, opt_level)?;
 
         let mut out_buf: MaybeUninit<LLVMMemoryBufferRef> = MaybeUninit::uninit();
 
         let ok = LLVMTargetMachineEmitToMemoryBuffer(
             machine,
             llvm_module,
-            LLVMCodeGenFileType::LLVMObjectFile,
+            file_type,
             error_buffer,
             out_buf.as_mut_ptr(),
         );
@@ -205,6 +285,11 @@ pub fn module_to_object(
     }
 }
 
+/// Runtime functions which are only looked up by the executors. The generated code doesn't
+/// reference them, so they have to be linked into the shared library explicitly.
+const EXECUTOR_RUNTIME_SYMBOLS: [&str; 2] =
+    ["cairo_native__take_trap", "cairo_native__set_trace_hook"];
+
 /// Links the passed object into a shared library, stored on the given path.
 pub fn object_to_shared_lib(object: &[u8], output_filename: &Path) -> Result<(), std::io::Error> {
     // linker seems to need a file and doesn't accept stdin
@@ -233,6 +318,9 @@ pub fn object_to_shared_lib(object: &[u8], output_filename: &Path) -> Result<(),
                 Cow::from(output_path),
                 "-lSystem".into(),
             ]);
+            for symbol in EXECUTOR_RUNTIME_SYMBOLS {
+                args.extend(["-u".into(), Cow::from(format!("_{symbol}"))]);
+            }
 
             if let Ok(extra_dir) = std::env::var("CAIRO_NATIVE_RUNTIME_LIBRARY") {
                 args.extend([Cow::from(extra_dir)]);
@@ -259,6 +347,9 @@ pub fn object_to_shared_lib(object: &[u8], output_filename: &Path) -> Result<(),
                 //"-lcairo_native_runtime".into(),
                 Cow::from(file_path),
             ]);
+            for symbol in EXECUTOR_RUNTIME_SYMBOLS {
+                args.extend(["-u".into(), Cow::from(symbol)]);
+            }
 
             if let Ok(extra_dir) = std::env::var("CAIRO_NATIVE_RUNTIME_LIBRARY") {
                 args.extend([Cow::from(extra_dir)]);
@@ -342,6 +433,7 @@ pub fn get_data_layout_rep() -> Result<String, CompileError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{context::NativeContext, utils::test::load_cairo};
 
     #[test]
     fn test_opt_level_default() {
@@ -377,4 +469,40 @@ mod tests {
         assert_eq!(OptLevel::from(3u8), OptLevel::Aggressive);
         assert_eq!(OptLevel::from(30u8), OptLevel::Aggressive);
     }
+
+    #[test]
+    fn test_emit_llvm_ir_and_assembly() {
+        let (_, program) = load_cairo! {
+            fn fib(a: felt252, b: felt252, n: felt252) -> felt252 {
+                match n {
+                    0 => a,
+                    _ => fib(b, a + b, n - 1),
+                }
+            }
+
+            fn run_test() -> felt252 {
+                fib(0, 1, 10)
+            }
+        };
+
+        let emit = || {
+            let native_context = NativeContext::new();
+            let module = native_context.compile(&program, None).unwrap();
+
+            let mlir = module.module().as_operation().to_string();
+            let llvm_ir = module_to_llvm_ir(module.module()).unwrap();
+            let assembly = module_to_assembly(module.module(), OptLevel::None).unwrap();
+            (mlir, llvm_ir, assembly)
+        };
+
+        let (mlir, llvm_ir, assembly) = emit();
+        assert!(mlir.contains("llvm.func"));
+        assert!(llvm_ir.contains("define"));
+        assert!(llvm_ir.contains("target triple = "));
+        assert!(llvm_ir.contains("target datalayout = "));
+        assert!(!assembly.is_empty());
+
+        // The outputs must be reproducible.
+        assert_eq!(emit(), (mlir, llvm_ir, assembly));
+    }
 }
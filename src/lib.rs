@@ -67,6 +67,7 @@
 //! ```txt
 //!  src
 //!  ├─ context.rs - The MLIR context wrapper, provides the compile method.
+//!  ├─ contract_class.rs - Starknet contract class loading and entry point lookup.
 //!  ├─ utils.rs - Internal utilities.
 //!  ├─ metadata/ - Metadata injector to use within the compilation process
 //!  ├─ executor/ - Code related to the executor of programs.
@@ -86,6 +87,7 @@
 //!  ├─ compiler.rs - The glue code of the compiler, has the codegen for the function signatures
 //!  and calls the libfunc codegen implementations.
 //!  ├─ error.rs - Error handling
+//!  ├─ statistics.rs - Compilation statistics
 //!  ├─ bin - Binary programs
 //!  ├─ types - Cairo to MLIR type information
 //! ```
@@ -94,14 +96,18 @@
 #![allow(clippy::missing_safety_doc)]
 
 pub use self::{
-    compiler::compile,
-    ffi::{module_to_object, object_to_shared_lib, LLVMCompileError, OptLevel},
+    compiler::{compile, compile_entry_points, find_unsupported_libfuncs, UnsupportedLibfunc},
+    ffi::{
+        module_to_assembly, module_to_llvm_ir, module_to_object, object_to_shared_lib,
+        LLVMCompileError, OptLevel,
+    },
 };
 
 pub(crate) mod block_ext;
 pub mod cache;
 mod compiler;
 pub mod context;
+pub mod contract_class;
 pub mod debug_info;
 pub mod error;
 pub mod execution_result;
@@ -112,6 +118,7 @@ pub mod metadata;
 pub mod module;
 pub mod starknet;
 pub mod starknet_stub;
+pub mod statistics;
 pub mod types;
 pub mod utils;
 pub mod values;
@@ -3,16 +3,24 @@
 //! Contains libfunc generation stuff (aka. the actual instructions).
 
 use crate::block_ext::BlockExt;
-use crate::{error::Error as CoreLibfuncBuilderError, metadata::MetadataStorage};
+use crate::{
+    error::{Error as CoreLibfuncBuilderError, TrapCode},
+    metadata::{runtime_bindings::RuntimeBindingsMeta, MetadataStorage},
+};
 use bumpalo::Bump;
 use cairo_lang_sierra::{
-    extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType},
+    extensions::{
+        array::ArrayConcreteLibfunc,
+        core::{CoreConcreteLibfunc, CoreLibfunc, CoreType},
+        debug::DebugConcreteLibfunc,
+        enm::EnumConcreteLibfunc,
+    },
     ids::FunctionId,
     program_registry::ProgramRegistry,
 };
 use melior::{
-    dialect::{arith, cf},
-    ir::{Block, BlockRef, Location, Module, Operation, Region, Value, ValueLike},
+    dialect::{arith, cf, func, llvm},
+    ir::{Block, BlockRef, Location, Module, Operation, Region, Type, Value, ValueLike},
     Context,
 };
 use std::{borrow::Cow, cell::Cell, error::Error, ops::Deref};
@@ -83,6 +91,14 @@ pub trait LibfuncBuilder {
     /// This is used by the compiler to check whether a statement is a function call and apply the
     /// tail recursion logic.
     fn is_function_call(&self) -> Option<&FunctionId>;
+
+    /// Return whether [`build`](Self::build) can generate the libfunc, instead of failing with
+    /// [`Error::UnsupportedLibfunc`](crate::error::Error::UnsupportedLibfunc).
+    fn is_supported(&self) -> bool;
+
+    /// Return whether [`build`](Self::build) may emit a trap. Function calls aren't included, since
+    /// whether they trap depends on the callee.
+    fn may_trap(&self) -> bool;
 }
 
 impl LibfuncBuilder for CoreConcreteLibfunc {
@@ -231,6 +247,28 @@ impl LibfuncBuilder for CoreConcreteLibfunc {
             _ => None,
         }
     }
+
+    fn is_supported(&self) -> bool {
+        match self {
+            Self::Gas(selector) => self::gas::check_supported(selector).is_ok(),
+            Self::StarkNet(selector) => self::starknet::check_supported(selector).is_ok(),
+            _ => true,
+        }
+    }
+
+    fn may_trap(&self) -> bool {
+        matches!(
+            self,
+            Self::Array(
+                ArrayConcreteLibfunc::Get(_)
+                    | ArrayConcreteLibfunc::PopFront(_)
+                    | ArrayConcreteLibfunc::PopFrontConsume(_)
+                    | ArrayConcreteLibfunc::SnapshotPopFront(_)
+                    | ArrayConcreteLibfunc::SnapshotPopBack(_)
+            ) | Self::Debug(DebugConcreteLibfunc::Print(_))
+                | Self::Enum(EnumConcreteLibfunc::Match(_) | EnumConcreteLibfunc::SnapshotMatch(_))
+        )
+    }
 }
 
 /// Helper struct which contains logic generation for extra MLIR blocks and branch operations to the
@@ -255,6 +293,12 @@ where
 
     pub(crate) branches: Vec<(&'this Block<'ctx>, Vec<BranchArg<'ctx, 'this>>)>,
     pub(crate) results: Vec<Vec<Cell<Option<Value<'ctx, 'this>>>>>,
+
+    /// The MLIR return types of the function being compiled.
+    pub(crate) return_types: &'this [Type<'ctx>],
+    /// Whether [`build_trap`](Self::build_trap) has been called, so that the compiler can check
+    /// that the libfunc reports it through [`LibfuncBuilder::may_trap`].
+    pub(crate) emitted_trap: Cell<bool>,
 }
 
 impl<'ctx, 'this> LibfuncHelper<'ctx, 'this>
@@ -295,6 +339,52 @@ where
         block_ref
     }
 
+    /// Report a trap to the runtime, then return from the current function with poisoned values.
+    ///
+    /// The executor discards the results and reports the trap instead, and every caller returns as
+    /// soon as its callee does, so no more user code runs after the trap. When the runtime bindings
+    /// aren't available it falls back to aborting.
+    pub(crate) fn build_trap(
+        &self,
+        context: &'ctx Context,
+        metadata: &mut MetadataStorage,
+        block: &'this Block<'ctx>,
+        code: TrapCode,
+        location: Location<'ctx>,
+    ) -> Result<(), CoreLibfuncBuilderError> {
+        self.emitted_trap.set(true);
+        match metadata.get_mut::<RuntimeBindingsMeta>() {
+            Some(runtime_bindings) => {
+                let code = block.const_int(context, location, code as u64, 64)?;
+                runtime_bindings.trap(context, self, block, code, location)?;
+                self.build_poisoned_return(block, location)
+            }
+            None => {
+                let k0 = block.const_int(context, location, 0, 1)?;
+                block.append_operation(cf::assert(context, k0, code.message(), location));
+                block.append_operation(llvm::unreachable(location));
+                Ok(())
+            }
+        }
+    }
+
+    /// Return from the current function with undefined values, which are only observed (and
+    /// discarded) by the executor after a trap.
+    pub(crate) fn build_poisoned_return(
+        &self,
+        block: &'this Block<'ctx>,
+        location: Location<'ctx>,
+    ) -> Result<(), CoreLibfuncBuilderError> {
+        let values = self
+            .return_types
+            .iter()
+            .map(|ty| block.append_op_result(llvm::undef(*ty, location)))
+            .collect::<Result<Vec<_>, _>>()?;
+        block.append_operation(func::r#return(&values, location));
+
+        Ok(())
+    }
+
     /// Creates an unconditional branching operation out of the libfunc and into the next statement.
     ///
     /// This method will also store the returned values so that they can be moved into the state and
@@ -540,6 +630,8 @@ mod tests {
             last_block: Cell::new(&last_block),
             branches: Vec::new(),
             results: Vec::new(),
+            return_types: &[],
+            emitted_trap: Cell::new(false),
         };
 
         // Create an integer type with 32 bits
@@ -610,6 +702,8 @@ mod tests {
             last_block: Cell::new(&last_block),
             branches: Vec::new(),
             results: Vec::new(),
+            return_types: &[],
+            emitted_trap: Cell::new(false),
         };
 
         // Create an integer type with 32 bits
@@ -689,6 +783,8 @@ mod tests {
             last_block: Cell::new(&last_block),
             branches: Vec::new(),
             results: Vec::new(),
+            return_types: &[],
+            emitted_trap: Cell::new(false),
         };
 
         // Create an integer type with 32 bits
@@ -6,7 +6,7 @@
 use super::LibfuncHelper;
 use crate::{
     block_ext::BlockExt,
-    error::Result,
+    error::{Result, TrapCode},
     metadata::{realloc_bindings::ReallocBindingsMeta, MetadataStorage},
     types::TypeBuilder,
     utils::ProgramRegistryExt,
@@ -476,13 +476,8 @@ pub fn build_get<'ctx, 'this>(
         let target_ptr = valid_block.append_op_result(ReallocBindingsMeta::realloc(
             context, target_ptr, elem_size, location,
         ))?;
-        assert_nonnull(
-            context,
-            valid_block,
-            location,
-            target_ptr,
-            "realloc returned nullptr",
-        )?;
+        let valid_block =
+            assert_nonnull(context, helper, metadata, valid_block, location, target_ptr)?;
 
         // TODO: Support clone-only types (those that are not copy).
         valid_block.memcpy(context, location, elem_ptr, target_ptr, elem_size);
@@ -573,13 +568,8 @@ pub fn build_pop_front<'ctx, 'this>(
         let target_ptr = valid_block.append_op_result(ReallocBindingsMeta::realloc(
             context, target_ptr, elem_size, location,
         ))?;
-        assert_nonnull(
-            context,
-            valid_block,
-            location,
-            target_ptr,
-            "realloc returned nullptr",
-        )?;
+        let valid_block =
+            assert_nonnull(context, helper, metadata, valid_block, location, target_ptr)?;
 
         valid_block.memcpy(context, location, ptr, target_ptr, elem_size);
 
@@ -702,13 +692,8 @@ pub fn build_snapshot_pop_back<'ctx, 'this>(
         let target_ptr = valid_block.append_op_result(ReallocBindingsMeta::realloc(
             context, target_ptr, elem_size, location,
         ))?;
-        assert_nonnull(
-            context,
-            valid_block,
-            location,
-            target_ptr,
-            "realloc returned nullptr",
-        )?;
+        let valid_block =
+            assert_nonnull(context, helper, metadata, valid_block, location, target_ptr)?;
 
         valid_block.memcpy(context, location, ptr, target_ptr, elem_size);
 
@@ -754,30 +739,31 @@ pub fn build_slice<'ctx, 'this>(
     let slice_since = entry.argument(2)?.into();
     let slice_length = entry.argument(3)?.into();
 
-    let slice_until = entry.append_op_result(arith::addi(slice_since, slice_length, location))?;
-
     let array_start =
         entry.extract_value(context, location, entry.argument(1)?.into(), len_ty, 1)?;
     let array_end = entry.extract_value(context, location, entry.argument(1)?.into(), len_ty, 2)?;
+    let array_len = entry.append_op_result(arith::subi(array_end, array_start, location))?;
 
-    let slice_since = entry.append_op_result(arith::addi(slice_since, array_start, location))?;
-    let slice_until = entry.append_op_result(arith::addi(slice_until, array_start, location))?;
-
+    // Compare against the remaining length instead of computing `since + length`, which may
+    // overflow.
     let lhs_bound = entry.append_op_result(arith::cmpi(
         context,
-        CmpiPredicate::Uge,
+        CmpiPredicate::Ule,
         slice_since,
-        array_start,
+        array_len,
         location,
     ))?;
+    let remaining_len = entry.append_op_result(arith::subi(array_len, slice_since, location))?;
     let rhs_bound = entry.append_op_result(arith::cmpi(
         context,
         CmpiPredicate::Ule,
-        slice_until,
-        array_end,
+        slice_length,
+        remaining_len,
         location,
     ))?;
 
+    let slice_since = entry.append_op_result(arith::addi(slice_since, array_start, location))?;
+
     let is_fully_contained = entry.append_op_result(arith::andi(lhs_bound, rhs_bound, location))?;
 
     let slice_block = helper.append_block(Block::new(&[]));
@@ -947,13 +933,15 @@ pub fn build_span_from_tuple<'ctx, 'this>(
     Ok(())
 }
 
+/// Trap if `ptr` is null, otherwise continue into the returned block.
 fn assert_nonnull<'ctx, 'this>(
     context: &'ctx Context,
+    helper: &LibfuncHelper<'ctx, 'this>,
+    metadata: &mut MetadataStorage,
     entry: &'this Block<'ctx>,
     location: Location<'ctx>,
     ptr: Value<'ctx, 'this>,
-    msg: &str,
-) -> Result<()> {
+) -> Result<&'this Block<'ctx>> {
     let null_ptr =
         entry.append_op_result(ods::llvm::mlir_zero(context, ptr.r#type(), location).into())?;
 
@@ -969,8 +957,27 @@ fn assert_nonnull<'ctx, 'this>(
         .into(),
     )?;
 
-    entry.append_operation(cf::assert(context, ptr_is_not_null, msg, location));
-    Ok(())
+    let cont_block = helper.append_block(Block::new(&[]));
+    let trap_block = helper.append_block(Block::new(&[]));
+    entry.append_operation(cf::cond_br(
+        context,
+        ptr_is_not_null,
+        cont_block,
+        trap_block,
+        &[],
+        &[],
+        location,
+    ));
+
+    helper.build_trap(
+        context,
+        metadata,
+        trap_block,
+        TrapCode::AllocationFailed,
+        location,
+    )?;
+
+    Ok(cont_block)
 }
 
 #[cfg(test)]
@@ -1012,6 +1019,38 @@ mod test {
         assert_eq!(result, [4u32].into());
     }
 
+    #[test]
+    fn run_append_many() {
+        let program = load_cairo! {
+            use array::ArrayTrait;
+
+            fn run_test() -> felt252 {
+                let mut numbers = ArrayTrait::new();
+                let mut i = 0;
+                loop {
+                    if i == 1000 {
+                        break;
+                    }
+                    numbers.append(i);
+                    i += 1;
+                };
+
+                let mut numbers = numbers.span();
+                let mut sum = 0;
+                loop {
+                    match numbers.pop_front() {
+                        Option::Some(x) => sum += *x,
+                        Option::None => { break; },
+                    }
+                };
+                sum
+            }
+        };
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(result, jit_enum!(0, jit_struct!(Felt::from(499500).into())));
+    }
+
     #[test]
     fn run_len() {
         let program = load_cairo!(
@@ -1030,6 +1069,21 @@ mod test {
         assert_eq!(result, 3u32.into());
     }
 
+    #[test]
+    fn run_len_empty() {
+        let program = load_cairo!(
+            use array::ArrayTrait;
+
+            fn run_test() -> u32 {
+                let numbers: Array<felt252> = ArrayTrait::new();
+                numbers.len()
+            }
+        );
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(result, 0u32.into());
+    }
+
     #[test]
     fn run_get() {
         let program = load_cairo!(
@@ -1120,6 +1174,43 @@ mod test {
         );
     }
 
+    #[test]
+    fn run_get_struct_bounds() {
+        let program = load_cairo!(
+            use array::ArrayTrait;
+
+            #[derive(Copy, Drop)]
+            struct Point {
+                x: u32,
+                y: felt252,
+            }
+
+            fn run_test(index: u32) -> Option<Point> {
+                let mut points = ArrayTrait::new();
+                points.append(Point { x: 1, y: 10 });
+                points.append(Point { x: 2, y: 20 });
+                points.append(Point { x: 3, y: 30 });
+
+                match points.get(index) {
+                    Option::Some(point) => Option::Some(*point.unbox()),
+                    Option::None => Option::None,
+                }
+            }
+        );
+        let r = |index: u32| run_program(&program, "run_test", &[index.into()]).return_value;
+
+        assert_eq!(
+            r(0),
+            jit_enum!(0, jit_struct!(1u32.into(), Felt::from(10).into()))
+        );
+        assert_eq!(
+            r(2),
+            jit_enum!(0, jit_struct!(3u32.into(), Felt::from(30).into()))
+        );
+        assert_eq!(r(3), jit_enum!(1, jit_struct!()));
+        assert_eq!(r(u32::MAX), jit_enum!(1, jit_struct!()));
+    }
+
     #[test]
     fn run_pop_front() {
         let program = load_cairo!(
@@ -1139,6 +1230,63 @@ mod test {
         assert_eq!(result, jit_enum!(0, jit_struct!(3u32.into())));
     }
 
+    #[test]
+    fn run_pop_front_empty() {
+        let program = load_cairo!(
+            use array::ArrayTrait;
+
+            fn run_test() -> (Option<u32>, Option<u32>) {
+                let mut numbers: Array<u32> = ArrayTrait::new();
+                let mut span = numbers.span();
+                let last = match span.pop_back() {
+                    Option::Some(x) => Option::Some(*x),
+                    Option::None => Option::None,
+                };
+                (numbers.pop_front(), last)
+            }
+        );
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(
+            result,
+            jit_struct!(jit_enum!(1, jit_struct!()), jit_enum!(1, jit_struct!()))
+        );
+    }
+
+    #[test]
+    fn run_span_pop_front_sum() {
+        let program = load_cairo!(
+            use array::ArrayTrait;
+
+            fn run_test() -> u32 {
+                let mut numbers = ArrayTrait::new();
+                let mut i = 1_u32;
+                loop {
+                    if i > 10 {
+                        break;
+                    }
+                    numbers.append(i);
+                    i += 1;
+                }
+
+                let mut span = numbers.span();
+                let mut sum = 0_u32;
+                loop {
+                    match span.pop_front() {
+                        Option::Some(x) => sum += *x,
+                        Option::None => {
+                            break;
+                        }
+                    }
+                }
+                sum
+            }
+        );
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(result, jit_enum!(0, jit_struct!(55u32.into())));
+    }
+
     #[test]
     fn run_pop_front_result() {
         let program = load_cairo!(
@@ -1290,6 +1438,62 @@ mod test {
         );
     }
 
+    #[test]
+    fn run_slice_bounds() {
+        let program = load_cairo!(
+            use array::ArrayTrait;
+            use array::SpanTrait;
+
+            fn run_test() -> (u32, u32, u32) {
+                let mut data: Array<u32> = ArrayTrait::new();
+                data.append(1_u32);
+                data.append(2_u32);
+                data.append(3_u32);
+                data.append(4_u32);
+                let sp = data.span();
+                let full = sp.slice(0, 4);
+                let empty = sp.slice(4, 0);
+                (full.len(), *full.at(3), empty.len())
+            }
+        );
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(
+            result,
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(4u32.into(), 4u32.into(), 0u32.into()))
+            )
+        );
+    }
+
+    #[test]
+    fn run_slice_overflow() {
+        let program = load_cairo!(
+            use array::ArrayTrait;
+            use array::SpanTrait;
+
+            fn run_test() -> u32 {
+                let mut data: Array<u32> = ArrayTrait::new();
+                data.append(1_u32);
+                data.append(2_u32);
+                data.append(3_u32);
+                data.append(4_u32);
+                let sp = data.span();
+                let slice = sp.slice(1, 0xFFFFFFFF_u32);
+                slice.len()
+            }
+        );
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(
+            result,
+            jit_panic!(JitValue::felt_str(
+                "1637570914057682275393755530660268060279989363"
+            ))
+        );
+    }
+
     #[test]
     fn run_span_from_tuple() {
         let program = load_cairo!(
@@ -1317,6 +1521,38 @@ mod test {
         );
     }
 
+    #[test]
+    fn run_span_from_tuple_index() {
+        let program = load_cairo!(
+            mod felt252_span_from_tuple {
+                pub extern fn span_from_tuple<T>(struct_like: Box<@T>) -> @Array<felt252> nopanic;
+            }
+
+            fn run_test() -> (u32, felt252, felt252, Option<felt252>) {
+                let span = felt252_span_from_tuple::span_from_tuple(BoxTrait::new(@(10, 20, 30)));
+                let out_of_bounds = match span.get(3) {
+                    Option::Some(x) => Option::Some(*x.unbox()),
+                    Option::None => Option::None,
+                };
+                (span.len(), *span[0], *span[2], out_of_bounds)
+            }
+        );
+        let result = run_program(&program, "run_test", &[]).return_value;
+
+        assert_eq!(
+            result,
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(
+                    3u32.into(),
+                    Felt::from(10).into(),
+                    Felt::from(30).into(),
+                    jit_enum!(1, jit_struct!()),
+                ))
+            )
+        );
+    }
+
     #[test]
     fn run_span_from_multi_tuple() {
         let program = load_cairo!(
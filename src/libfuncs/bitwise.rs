@@ -57,6 +57,11 @@ mod test {
                 bitwise(lhs, rhs)
             }
         };
+        static ref BITWISE_U32: (String, Program) = load_cairo! {
+            fn run_test(lhs: u32, rhs: u32) -> (u32, u32, u32) {
+                (lhs & rhs, lhs ^ rhs, lhs | rhs)
+            }
+        };
     }
 
     #[test]
@@ -107,5 +112,38 @@ mod test {
                 0xFFFFFFFF_FFFFFFFF_FFFFFFFF_FFFFFFFFu128.into()
             )
         );
+        assert_eq!(
+            r(
+                0x0F0F0F0F_00FF00FF_12345678_FFFF0000u128.into(),
+                0x00FF00FF_0F0F0F0F_87654321_00FFFF00u128.into(),
+            ),
+            jit_struct!(
+                0x000F000F_000F000F_02244220_00FF0000u128.into(),
+                0x0FF00FF0_0FF00FF0_95511559_FF00FF00u128.into(),
+                0x0FFF0FFF_0FFF0FFF_97755779_FFFFFF00u128.into()
+            )
+        );
+    }
+
+    #[test]
+    fn bitwise_u32() {
+        let r = |lhs, rhs| run_program(&BITWISE_U32, "run_test", &[lhs, rhs]).return_value;
+
+        assert_eq!(
+            r(0x00000000u32.into(), 0xFFFFFFFFu32.into()),
+            jit_struct!(
+                0x00000000u32.into(),
+                0xFFFFFFFFu32.into(),
+                0xFFFFFFFFu32.into()
+            )
+        );
+        assert_eq!(
+            r(0x12345678u32.into(), 0x87654321u32.into()),
+            jit_struct!(
+                0x02244220u32.into(),
+                0x95511559u32.into(),
+                0x97755779u32.into()
+            )
+        );
     }
 }
@@ -15,7 +15,7 @@ use cairo_lang_sierra::{
     program_registry::ProgramRegistry,
 };
 use melior::{
-    dialect::{arith, llvm},
+    dialect::arith,
     ir::{r#type::IntegerType, Block, Location},
     Context,
 };
@@ -80,30 +80,18 @@ enum BoolOp {
 /// Generate MLIR operations for the `bool_not_impl` libfunc.
 #[allow(clippy::too_many_arguments)]
 fn build_bool_binary<'ctx, 'this>(
-    context: &'ctx Context,
-    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    _context: &'ctx Context,
+    _registry: &ProgramRegistry<CoreType, CoreLibfunc>,
     entry: &'this Block<'ctx>,
     location: Location<'ctx>,
     helper: &LibfuncHelper<'ctx, 'this>,
-    metadata: &mut MetadataStorage,
-    info: &SignatureOnlyConcreteLibfunc,
+    _metadata: &mut MetadataStorage,
+    _info: &SignatureOnlyConcreteLibfunc,
     bin_op: BoolOp,
 ) -> Result<()> {
-    let enum_ty = registry.get_type(&info.param_signatures()[0].ty)?;
-    let tag_bits = enum_ty
-        .variants()
-        .expect("bool is a enum and has variants")
-        .len()
-        .next_power_of_two()
-        .trailing_zeros();
-    let tag_ty = IntegerType::new(context, tag_bits).into();
-
-    let lhs = entry.argument(0)?.into();
-    let rhs = entry.argument(1)?.into();
-
-    let lhs_tag = entry.extract_value(context, location, lhs, tag_ty, 0)?;
-
-    let rhs_tag = entry.extract_value(context, location, rhs, tag_ty, 0)?;
+    // Booleans are C-style enums, which are represented by their tag alone.
+    let lhs_tag = entry.argument(0)?.into();
+    let rhs_tag = entry.argument(1)?.into();
 
     let new_tag_value = match bin_op {
         BoolOp::And => entry.append_op_result(arith::andi(lhs_tag, rhs_tag, location))?,
@@ -111,20 +99,7 @@ fn build_bool_binary<'ctx, 'this>(
         BoolOp::Or => entry.append_op_result(arith::ori(lhs_tag, rhs_tag, location))?,
     };
 
-    let res = entry.append_op_result(llvm::undef(
-        enum_ty.build(
-            context,
-            helper,
-            registry,
-            metadata,
-            &info.param_signatures()[0].ty,
-        )?,
-        location,
-    ))?;
-
-    let res = entry.insert_value(context, location, res, new_tag_value, 0)?;
-
-    entry.append_operation(helper.br(0, &[res], location));
+    entry.append_operation(helper.br(0, &[new_tag_value], location));
     Ok(())
 }
 
@@ -135,7 +110,7 @@ pub fn build_bool_not<'ctx, 'this>(
     entry: &'this Block<'ctx>,
     location: Location<'ctx>,
     helper: &LibfuncHelper<'ctx, 'this>,
-    metadata: &mut MetadataStorage,
+    _metadata: &mut MetadataStorage,
     info: &SignatureOnlyConcreteLibfunc,
 ) -> Result<()> {
     let enum_ty = registry.get_type(&info.param_signatures()[0].ty)?;
@@ -147,26 +122,14 @@ pub fn build_bool_not<'ctx, 'this>(
         .trailing_zeros();
     let tag_ty = IntegerType::new(context, tag_bits).into();
 
-    let value = entry.argument(0)?.into();
-    let tag_value = entry.extract_value(context, location, value, tag_ty, 0)?;
+    // Booleans are C-style enums, which are represented by their tag alone.
+    let tag_value = entry.argument(0)?.into();
 
     let const_1 = entry.const_int_from_type(context, location, 1, tag_ty)?;
 
     let new_tag_value = entry.append_op_result(arith::xori(tag_value, const_1, location))?;
 
-    let res = entry.append_op_result(llvm::undef(
-        enum_ty.build(
-            context,
-            helper,
-            registry,
-            metadata,
-            &info.param_signatures()[0].ty,
-        )?,
-        location,
-    ))?;
-    let res = entry.insert_value(context, location, res, new_tag_value, 0)?;
-
-    entry.append_operation(helper.br(0, &[res], location));
+    entry.append_operation(helper.br(0, &[new_tag_value], location));
     Ok(())
 }
 
@@ -180,7 +143,6 @@ pub fn build_bool_to_felt252<'ctx, 'this>(
     metadata: &mut MetadataStorage,
     info: &SignatureOnlyConcreteLibfunc,
 ) -> Result<()> {
-    let enum_ty = registry.get_type(&info.param_signatures()[0].ty)?;
     let felt252_ty = registry.build_type(
         context,
         helper,
@@ -189,16 +151,8 @@ pub fn build_bool_to_felt252<'ctx, 'this>(
         &info.branch_signatures()[0].vars[0].ty,
     )?;
 
-    let tag_bits = enum_ty
-        .variants()
-        .expect("bool is a enum and has variants")
-        .len()
-        .next_power_of_two()
-        .trailing_zeros();
-    let tag_ty = IntegerType::new(context, tag_bits).into();
-
-    let value = entry.argument(0)?.into();
-    let tag_value = entry.extract_value(context, location, value, tag_ty, 0)?;
+    // Booleans are C-style enums, which are represented by their tag alone.
+    let tag_value = entry.argument(0)?.into();
 
     let result = entry.append_op_result(arith::extui(tag_value, felt252_ty, location))?;
 
@@ -373,4 +327,29 @@ mod test {
         let result = run_program(&program, "run_test", &[jit_enum!(0, jit_struct!())]).return_value;
         assert_eq!(result, JitValue::Felt252(0.into()));
     }
+
+    #[test]
+    fn bool_truth_table() {
+        let program = load_cairo!(
+            fn run_test(a: bool, b: bool) -> (bool, bool, bool, bool, felt252) {
+                (a && b, a || b, a ^ b, !a, bool_to_felt252(a))
+            }
+        );
+
+        let to_jit = |value: bool| jit_enum!(value as usize, jit_struct!());
+
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let result = run_program(&program, "run_test", &[to_jit(a), to_jit(b)]).return_value;
+            assert_eq!(
+                result,
+                jit_struct!(
+                    to_jit(a & b),
+                    to_jit(a | b),
+                    to_jit(a ^ b),
+                    to_jit(!a),
+                    JitValue::Felt252((a as u8).into()),
+                )
+            );
+        }
+    }
 }
@@ -184,7 +184,7 @@ fn build_forward_snapshot<'ctx, 'this>(
 #[cfg(test)]
 mod test {
     use crate::{
-        utils::test::{load_cairo, run_program_assert_output},
+        utils::test::{jit_enum, jit_struct, load_cairo, run_program_assert_output},
         values::JitValue,
     };
 
@@ -220,6 +220,76 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], JitValue::Uint32(2));
     }
 
+    #[test]
+    fn run_box_unbox_felt252() {
+        let program = load_cairo! {
+            use box::BoxTrait;
+
+            fn run_test(x: felt252) -> felt252 {
+                BoxTrait::new(x).unbox()
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[JitValue::felt_str("-1")],
+            JitValue::felt_str("-1"),
+        );
+    }
+
+    #[test]
+    fn run_box_unbox_struct() {
+        let program = load_cairo! {
+            use box::BoxTrait;
+
+            #[derive(Drop)]
+            struct Triple {
+                a: u8,
+                b: felt252,
+                c: u64,
+            }
+
+            fn run_test() -> Triple {
+                BoxTrait::new(Triple { a: 1, b: 2, c: 3 }).unbox()
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_struct!(1u8.into(), JitValue::Felt252(2.into()), 3u64.into()),
+        );
+    }
+
+    #[test]
+    fn run_box_unbox_loop() {
+        let program = load_cairo! {
+            use box::BoxTrait;
+
+            fn run_test() -> felt252 {
+                let mut acc = BoxTrait::new(0);
+                let mut i = 0;
+                loop {
+                    if i == 10000 {
+                        break;
+                    }
+                    acc = BoxTrait::new(acc.unbox() + i);
+                    i += 1;
+                };
+                acc.unbox()
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_enum!(0, jit_struct!(JitValue::Felt252(49995000.into()))),
+        );
+    }
+
     #[test]
     fn box_unbox_stack_allocated_enum_single() {
         let program = load_cairo! {
@@ -207,4 +207,45 @@ mod test {
             jit_panic!(Felt::from_bytes_be_slice(b"Option::unwrap failed.")),
         );
     }
+
+    #[test]
+    fn bytes31_try_from_felt252_bounds() {
+        let max = Felt::from_dec_str(
+            "452312848583266388373324160190187140051835877600158453279131187530910662655",
+        )
+        .unwrap();
+
+        run_program_assert_output(
+            &BYTES31_ROUNDTRIP,
+            "run_test",
+            &[max.into()],
+            jit_enum!(0, jit_struct!(max.into())),
+        );
+        run_program_assert_output(
+            &BYTES31_ROUNDTRIP,
+            "run_test",
+            &[(max + Felt::ONE).into()],
+            jit_panic!(Felt::from_bytes_be_slice(b"Option::unwrap failed.")),
+        );
+    }
+
+    #[test]
+    fn byte_array_append_len() {
+        let program = load_cairo! {
+            fn run_test() -> u32 {
+                let mut value: ByteArray = "This string is longer than a single bytes31 word";
+                let suffix: ByteArray = ", and then some more";
+                value.append(@suffix);
+                value.append_byte('!');
+                value.len()
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_enum!(0, jit_struct!(69u32.into())),
+        );
+    }
 }
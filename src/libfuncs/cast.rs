@@ -7,7 +7,7 @@ use crate::{
     block_ext::BlockExt,
     error::{Error, Result, SierraAssertError},
     metadata::{prime_modulo::PrimeModuloMeta, MetadataStorage},
-    types::TypeBuilder,
+    types::{felt252::register_prime_modulo_meta, TypeBuilder},
 };
 use cairo_lang_sierra::{
     extensions::{
@@ -27,7 +27,7 @@ use melior::{
     Context,
 };
 use num_bigint::{BigInt, ToBigInt};
-use num_traits::Euclid;
+use num_traits::{Euclid, Signed};
 use starknet_types_core::felt::Felt;
 
 /// Select and call the correct libfunc builder function from the selector.
@@ -296,7 +296,39 @@ pub fn build_upcast<'ctx, 'this>(
     let dst_width = dst_ty
         .integer_width()
         .ok_or_else(|| Error::SierraAssert(SierraAssertError::Cast))?;
-    assert!(src_width <= dst_width);
+    if src_width > dst_width {
+        // Bounded ints are stored as felts, which are wider than the integers their ranges may fit
+        // in. Sierra has already checked that the range fits in the target type, so the value only
+        // needs to be truncated.
+        let CoreTypeConcrete::BoundedInt(src_info) = src_ty else {
+            return Err(Error::SierraAssert(SierraAssertError::Cast));
+        };
+
+        let mut value: melior::ir::Value = entry.argument(0)?.into();
+        if src_info.range.lower.is_negative() {
+            // Negative values are stored as `PRIME - |value|`. Subtracting the prime turns them
+            // into their two's complement representation.
+            let prime = register_prime_modulo_meta(metadata).prime().clone();
+
+            let half_prime =
+                entry.const_int_from_type(context, location, prime.clone().shr(1), src_type)?;
+            let prime = entry.const_int_from_type(context, location, prime, src_type)?;
+
+            let is_neg = entry.append_op_result(arith::cmpi(
+                context,
+                CmpiPredicate::Ugt,
+                value,
+                half_prime,
+                location,
+            ))?;
+            let neg_value = entry.append_op_result(arith::subi(value, prime, location))?;
+            value = entry.append_op_result(arith::select(is_neg, neg_value, value, location))?;
+        }
+
+        let result = entry.append_op_result(arith::trunci(value, dst_type, location))?;
+        entry.append_operation(helper.br(0, &[result], location));
+        return Ok(());
+    }
 
     let is_signed = src_ty
         .is_integer_signed()
@@ -395,11 +427,13 @@ pub fn build_upcast<'ctx, 'this>(
 #[cfg(test)]
 mod test {
     use crate::{
-        utils::test::{jit_enum, jit_struct, load_cairo, run_program_assert_output},
+        utils::test::{jit_enum, jit_struct, load_cairo, run_program, run_program_assert_output},
         values::JitValue,
     };
-    use cairo_lang_sierra::program::Program;
+    use cairo_lang_sierra::{extensions::utils::Range, program::Program, ProgramParser};
     use lazy_static::lazy_static;
+    use num_bigint::BigInt;
+    use starknet_types_core::felt::Felt;
 
     lazy_static! {
         static ref DOWNCAST: (String, Program) = load_cairo! {
@@ -423,6 +457,13 @@ mod test {
                 )
             }
         };
+        static ref DOWNCAST_BOUNDS: (String, Program) = load_cairo! {
+            use core::integer::downcast;
+
+            fn run_test(v16: u16, v252: felt252) -> (Option<u8>, Option<u8>) {
+                (downcast(v16), downcast(v252))
+            }
+        };
         static ref UPCAST: (String, Program) = load_cairo! {
             use core::integer::upcast;
 
@@ -485,6 +526,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn downcast_bounds() {
+        run_program_assert_output(
+            &DOWNCAST_BOUNDS,
+            "run_test",
+            &[255u16.into(), JitValue::felt_str("255")],
+            jit_struct!(jit_enum!(0, 255u8.into()), jit_enum!(0, 255u8.into())),
+        );
+        run_program_assert_output(
+            &DOWNCAST_BOUNDS,
+            "run_test",
+            &[256u16.into(), JitValue::felt_str("256")],
+            jit_struct!(jit_enum!(1, jit_struct!()), jit_enum!(1, jit_struct!())),
+        );
+        run_program_assert_output(
+            &DOWNCAST_BOUNDS,
+            "run_test",
+            &[u16::MAX.into(), JitValue::felt_str("-1")],
+            jit_struct!(jit_enum!(1, jit_struct!()), jit_enum!(1, jit_struct!())),
+        );
+        run_program_assert_output(
+            &DOWNCAST_BOUNDS,
+            "run_test",
+            &[
+                0u16.into(),
+                JitValue::felt_str("1606938044258990275541962092341162602522202993782792835301376"),
+            ],
+            jit_struct!(jit_enum!(0, 0u8.into()), jit_enum!(1, jit_struct!())),
+        );
+    }
+
     #[test]
     fn upcast() {
         run_program_assert_output(
@@ -690,4 +762,82 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn upcast_same_type() {
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type u128 = u128;
+
+                    libfunc upcast<u128, u128> = upcast<u128, u128>;
+                    libfunc store_temp<u128> = store_temp<u128>;
+
+                    upcast<u128, u128>([0]) -> ([1]);
+                    store_temp<u128>([1]) -> ([1]);
+                    return([1]);
+
+                    program::program::run_test@0([0]: u128) -> (u128);
+                "#,
+            )
+            .unwrap();
+
+        let result = run_program(
+            &("program".to_string(), program),
+            "run_test",
+            &[u128::MAX.into()],
+        );
+        assert_eq!(result.return_value, u128::MAX.into());
+    }
+
+    #[test]
+    fn upcast_narrowing_width() {
+        // Bounded ints are stored using 252 bits, but Sierra accepts the upcast because their
+        // range fits in the target type.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type BoundedIntU = BoundedInt<0, 10>;
+                    type BoundedIntS = BoundedInt<-10, 10>;
+                    type u8 = u8;
+                    type i8 = i8;
+                    type Tuple<u8, i8> = Struct<ut@Tuple, u8, i8>;
+
+                    libfunc upcast<BoundedIntU, u8> = upcast<BoundedIntU, u8>;
+                    libfunc upcast<BoundedIntS, i8> = upcast<BoundedIntS, i8>;
+                    libfunc struct_construct<Tuple<u8, i8>> = struct_construct<Tuple<u8, i8>>;
+                    libfunc store_temp<Tuple<u8, i8>> = store_temp<Tuple<u8, i8>>;
+
+                    upcast<BoundedIntU, u8>([0]) -> ([2]);
+                    upcast<BoundedIntS, i8>([1]) -> ([3]);
+                    struct_construct<Tuple<u8, i8>>([2], [3]) -> ([4]);
+                    store_temp<Tuple<u8, i8>>([4]) -> ([4]);
+                    return([4]);
+
+                    program::program::run_test@0([0]: BoundedIntU, [1]: BoundedIntS) -> (Tuple<u8, i8>);
+                "#,
+            )
+            .unwrap();
+        let program = ("program".to_string(), program);
+
+        let bounded_int = |value: i32, lower: i32, upper: i32| JitValue::BoundedInt {
+            value: Felt::from(value),
+            range: Range {
+                lower: BigInt::from(lower),
+                upper: BigInt::from(upper),
+            },
+        };
+
+        for (unsigned, signed) in [(0, 0), (10, 10), (3, -1), (7, -10)] {
+            run_program_assert_output(
+                &program,
+                "run_test",
+                &[bounded_int(unsigned, 0, 11), bounded_int(signed, -10, 11)],
+                jit_struct!(
+                    JitValue::Uint8(unsigned as u8),
+                    JitValue::Sint8(signed as i8)
+                ),
+            );
+        }
+    }
 }
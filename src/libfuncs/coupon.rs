@@ -1,7 +1,6 @@
-//! # Branch alignment libfunc
+//! # Coupon libfuncs
 //!
-//! Natively compiled code doesn't need branch alignment because it has no notion of segments.
-//! Because of this, this libfunc is a no-op.
+//! Coupons are zero-sized in natively compiled code, so buying and refunding them are no-ops.
 
 use super::LibfuncHelper;
 use crate::{
@@ -94,3 +93,40 @@ pub fn build_refund<'ctx, 'this>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{utils::test::run_program, values::JitValue};
+    use cairo_lang_sierra::ProgramParser;
+
+    #[test]
+    fn coupon_buy_refund() {
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+                    type Coupon = Coupon<user@program::program::callee>;
+
+                    libfunc coupon_buy = coupon_buy<user@program::program::callee>;
+                    libfunc coupon_refund = coupon_refund<user@program::program::callee>;
+                    libfunc const_42 = felt252_const<42>;
+
+                    coupon_buy() -> ([0]);
+                    coupon_refund([0]) -> ();
+                    const_42() -> ([1]);
+                    return([1]);
+                    const_42() -> ([0]);
+                    return([0]);
+
+                    program::program::run_test@0() -> (felt252);
+                    program::program::callee@4() -> (felt252);
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            run_program(&("program".to_string(), program), "run_test", &[]).return_value,
+            JitValue::felt_str("42"),
+        );
+    }
+}
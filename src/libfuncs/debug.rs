@@ -12,7 +12,7 @@
 use super::LibfuncHelper;
 use crate::block_ext::BlockExt;
 use crate::{
-    error::Result,
+    error::{Result, TrapCode},
     metadata::{runtime_bindings::RuntimeBindingsMeta, MetadataStorage},
 };
 use cairo_lang_sierra::{
@@ -113,14 +113,28 @@ pub fn build_print<'ctx>(
         k0,
         location,
     ))?;
-    cf::assert(
+
+    let cont_block = helper.append_block(Block::new(&[]));
+    let trap_block = helper.append_block(Block::new(&[]));
+    entry.append_operation(cf::cond_br(
         context,
         return_code_is_ok,
-        "Print libfunc invocation failed.",
+        cont_block,
+        trap_block,
+        &[],
+        &[],
         location,
-    );
+    ));
+
+    helper.build_trap(
+        context,
+        metadata,
+        trap_block,
+        TrapCode::DebugPrintFailed,
+        location,
+    )?;
 
-    entry.append_operation(helper.br(0, &[], location));
+    cont_block.append_operation(helper.br(0, &[], location));
 
     Ok(())
 }
@@ -68,3 +68,64 @@ pub fn build<'ctx, 'this>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        utils::test::{jit_struct, load_cairo, run_program_assert_output},
+        values::JitValue,
+    };
+    use cairo_lang_sierra::{
+        extensions::core::{CoreLibfunc, CoreType},
+        program_registry::ProgramRegistry,
+        ProgramParser,
+    };
+
+    #[test]
+    fn dup_struct_of_felts() {
+        let program = load_cairo! {
+            #[derive(Copy, Drop)]
+            struct Point {
+                x: felt252,
+                y: felt252,
+            }
+
+            fn run_test() -> (Point, Point) {
+                let p = Point { x: 1, y: 2 };
+                (p, p)
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_struct!(
+                jit_struct!(JitValue::Felt252(1.into()), JitValue::Felt252(2.into())),
+                jit_struct!(JitValue::Felt252(1.into()), JitValue::Felt252(2.into())),
+            ),
+        );
+    }
+
+    #[test]
+    fn dup_array_rejected() {
+        // Arrays aren't duplicatable, so the declaration never reaches the compiler.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+                    type Array<felt252> = Array<felt252>;
+
+                    libfunc dup<Array<felt252>> = dup<Array<felt252>>;
+
+                    dup<Array<felt252>>([0]) -> ([0], [1]);
+                    return([0], [1]);
+
+                    program::program::run_test@0([0]: Array<felt252>) -> (Array<felt252>, Array<felt252>);
+                "#,
+            )
+            .unwrap();
+
+        assert!(ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).is_err());
+    }
+}
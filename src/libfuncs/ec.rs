@@ -653,6 +653,13 @@ mod test {
                 ec_point_unwrap(point)
             }
         };
+        static ref EC_MUL: (String, Program) = load_cairo! {
+            use core::ec::{EcPoint, EcPointTrait};
+
+            fn run_test(point: EcPoint, scalar: felt252) -> EcPoint {
+                point.mul(scalar)
+            }
+        };
         static ref EC_POINT_ZERO: (String, Program) = load_cairo! {
             use core::ec::{ec_point_zero, EcPoint};
 
@@ -884,6 +891,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn ec_point_nz_constructors() {
+        let (x, y) = (
+            Felt::from_dec_str(
+                "874739451078007766457464989774322083649278607533249481151382481072868806602",
+            )
+            .unwrap(),
+            Felt::from_dec_str(
+                "152666792071518830868575557812948353041420400780739481342941381225525861407",
+            )
+            .unwrap(),
+        );
+
+        // The generator is on the curve.
+        run_program_assert_output(
+            &EC_POINT_TRY_NEW_NZ,
+            "run_test",
+            &[x.into(), y.into()],
+            jit_enum!(0, JitValue::EcPoint(x, y)),
+        );
+        // `(1, 1)` is not.
+        run_program_assert_output(
+            &EC_POINT_TRY_NEW_NZ,
+            "run_test",
+            &[Felt::ONE.into(), Felt::ONE.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        // `5^3 + 5 + beta` is not a quadratic residue.
+        run_program_assert_output(
+            &EC_POINT_FROM_X_NZ,
+            "run_test",
+            &[Felt::from(5).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn ec_point_unwrap() {
         fn parse(x: &str) -> Felt {
@@ -927,4 +970,43 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn ec_mul_generator() {
+        let generator = JitValue::EcPoint(
+            Felt::from_dec_str(
+                "874739451078007766457464989774322083649278607533249481151382481072868806602",
+            )
+            .unwrap(),
+            Felt::from_dec_str(
+                "152666792071518830868575557812948353041420400780739481342941381225525861407",
+            )
+            .unwrap(),
+        );
+
+        run_program_assert_output(
+            &EC_MUL,
+            "run_test",
+            &[generator.clone(), Felt::ONE.into()],
+            jit_enum!(0, jit_struct!(generator.clone())),
+        );
+        run_program_assert_output(
+            &EC_MUL,
+            "run_test",
+            &[generator, Felt::from(2).into()],
+            jit_enum!(
+                0,
+                jit_struct!(JitValue::EcPoint(
+                    Felt::from_dec_str(
+                        "3324833730090626974525872402899302150520188025637965566623476530814354734325",
+                    )
+                    .unwrap(),
+                    Felt::from_dec_str(
+                        "3147007486456030910661996439995670279305852583596209647900952752170983517249",
+                    )
+                    .unwrap(),
+                ))
+            ),
+        );
+    }
 }
@@ -5,7 +5,7 @@
 use super::LibfuncHelper;
 use crate::{
     block_ext::BlockExt,
-    error::{Error, Result},
+    error::{Error, Result, TrapCode},
     metadata::{enum_snapshot_variants::EnumSnapshotVariantsMeta, MetadataStorage},
     types::TypeBuilder,
 };
@@ -20,12 +20,8 @@ use cairo_lang_sierra::{
     program_registry::ProgramRegistry,
 };
 use melior::{
-    dialect::{arith, cf, llvm, ods},
-    ir::{
-        attribute::{DenseI64ArrayAttribute, IntegerAttribute},
-        r#type::IntegerType,
-        Block, Location, Value,
-    },
+    dialect::{arith, llvm, ods},
+    ir::{attribute::IntegerAttribute, r#type::IntegerType, Block, Location, Value},
     Context,
 };
 use std::num::TryFromIntError;
@@ -110,6 +106,25 @@ pub fn build_enum_value<'ctx, 'this>(
     Ok(match variant_tys.len() {
         0 => panic!("attempt to initialize a zero-variant enum"),
         1 => payload_value,
+        _ if type_info.is_memory_allocated(registry) && payload_type_info.is_zst(registry) => {
+            // The tag is the only meaningful data when the payload is zero-sized, and it always
+            // lives at offset zero. It can be written directly into the enum's representation
+            // instead of going through the stack to reinterpret the concrete variant.
+            let enum_ty = type_info.build(context, helper, registry, metadata, enum_type)?;
+            let tag_val = entry.const_int(
+                context,
+                location,
+                variant_index,
+                (8 * layout.align()) as u32,
+            )?;
+
+            let val = entry.append_op_result(llvm::undef(enum_ty, location))?;
+            entry.insert_value(context, location, val, tag_val, 0)?
+        }
+        // C-style enums are represented by their tag alone.
+        _ if !type_info.is_memory_allocated(registry) => {
+            entry.const_int_from_type(context, location, variant_index, tag_ty)?
+        }
         _ => {
             let enum_ty = llvm::r#type::r#struct(
                 context,
@@ -142,32 +157,28 @@ pub fn build_enum_value<'ctx, 'this>(
             let val = entry.append_op_result(llvm::undef(enum_ty, location))?;
             let val = entry.insert_value(context, location, val, tag_val, 0)?;
 
-            let mut val = if payload_type_info.is_zst(registry) {
+            let val = if payload_type_info.is_zst(registry) {
                 val
             } else {
                 entry.insert_value(context, location, val, payload_value, 1)?
             };
 
-            if type_info.is_memory_allocated(registry) {
-                let stack_ptr = helper.init_block().alloca1(
-                    context,
-                    location,
-                    type_info.build(context, helper, registry, metadata, enum_type)?,
-                    Some(layout.align()),
-                )?;
-
-                // Convert the enum from the concrete variant to the internal representation.
-                entry.store(context, location, stack_ptr, val, Some(layout.align()))?;
-                val = entry.load(
-                    context,
-                    location,
-                    stack_ptr,
-                    type_info.build(context, helper, registry, metadata, enum_type)?,
-                    Some(layout.align()),
-                )?;
-            };
+            let stack_ptr = helper.init_block().alloca1(
+                context,
+                location,
+                type_info.build(context, helper, registry, metadata, enum_type)?,
+                Some(layout.align()),
+            )?;
 
-            val
+            // Convert the enum from the concrete variant to the internal representation.
+            entry.store(context, location, stack_ptr, val, Some(layout.align()))?;
+            entry.load(
+                context,
+                location,
+                stack_ptr,
+                type_info.build(context, helper, registry, metadata, enum_type)?,
+                Some(layout.align()),
+            )?
         }
     })
 }
@@ -196,14 +207,6 @@ pub fn build_from_bounded_int<'ctx, 'this>(
     // we assume its never memory allocated since its always a enum with only a tag
     assert!(!enum_type.is_memory_allocated(registry));
 
-    let enum_ty = enum_type.build(
-        context,
-        helper,
-        registry,
-        metadata,
-        &info.branch_signatures()[0].vars[0].ty,
-    )?;
-
     let tag_bits = info.n_variants.next_power_of_two().trailing_zeros();
     let tag_type = IntegerType::new(context, tag_bits);
 
@@ -223,10 +226,8 @@ pub fn build_from_bounded_int<'ctx, 'this>(
         }
     };
 
-    let value = entry.append_op_result(llvm::undef(enum_ty, location))?;
-    let value = entry.insert_value(context, location, value, tag_value, 0)?;
-
-    entry.append_operation(helper.br(0, &[value], location));
+    // C-style enums are represented by their tag alone.
+    entry.append_operation(helper.br(0, &[tag_value], location));
 
     Ok(())
 }
@@ -248,17 +249,15 @@ pub fn build_match<'ctx, 'this>(
         0 => {
             // The Cairo compiler will generate an enum match for enums without variants, so this
             // case cannot be a compile-time error. We're assuming that even though it's been
-            // generated, it's just dead code and can be made into an assertion that always fails.
+            // generated, it's just dead code and can be made into a trap.
 
-            let k0 = entry.const_int(context, location, 0, 1)?;
-            entry.append_operation(cf::assert(
+            helper.build_trap(
                 context,
-                k0,
-                "attempt to match a zero-variant enum",
+                metadata,
+                entry,
+                TrapCode::ZeroVariantEnumMatch,
                 location,
-            ));
-
-            entry.append_operation(llvm::unreachable(location));
+            )?;
         }
         1 => {
             entry.append_operation(helper.br(0, &[entry.argument(0)?.into()], location));
@@ -297,18 +296,8 @@ pub fn build_match<'ctx, 'this>(
 
                 (Some(stack_ptr), tag_val)
             } else {
-                let tag_val = entry
-                    .append_operation(llvm::extract_value(
-                        context,
-                        entry.argument(0)?.into(),
-                        DenseI64ArrayAttribute::new(context, &[0]),
-                        tag_ty,
-                        location,
-                    ))
-                    .result(0)?
-                    .into();
-
-                (None, tag_val)
+                // C-style enums are represented by their tag alone.
+                (None, entry.argument(0)?.into())
             };
 
             let default_block = helper.append_block(Block::new(&[]));
@@ -336,24 +325,13 @@ pub fn build_match<'ctx, 'this>(
             )?);
 
             // Default block.
-            {
-                let val = default_block
-                    .append_operation(arith::constant(
-                        context,
-                        IntegerAttribute::new(IntegerType::new(context, 1).into(), 0).into(),
-                        location,
-                    ))
-                    .result(0)?
-                    .into();
-
-                default_block.append_operation(cf::assert(
-                    context,
-                    val,
-                    "Invalid enum tag.",
-                    location,
-                ));
-                default_block.append_operation(llvm::unreachable(location));
-            }
+            helper.build_trap(
+                context,
+                metadata,
+                default_block,
+                TrapCode::InvalidEnumTag,
+                location,
+            )?;
 
             // Enum variants.
             for (i, (block, (payload_ty, _))) in
@@ -419,17 +397,15 @@ pub fn build_snapshot_match<'ctx, 'this>(
         0 => {
             // The Cairo compiler will generate an enum match for enums without variants, so this
             // case cannot be a compile-time error. We're assuming that even though it's been
-            // generated, it's just dead code and can be made into an assertion that always fails.
+            // generated, it's just dead code and can be made into a trap.
 
-            let k0 = entry.const_int(context, location, 0, 1)?;
-            entry.append_operation(cf::assert(
+            helper.build_trap(
                 context,
-                k0,
-                "attempt to match a zero-variant enum",
+                metadata,
+                entry,
+                TrapCode::ZeroVariantEnumMatch,
                 location,
-            ));
-
-            entry.append_operation(llvm::unreachable(location));
+            )?;
         }
         1 => {
             entry.append_operation(helper.br(0, &[entry.argument(0)?.into()], location));
@@ -468,10 +444,8 @@ pub fn build_snapshot_match<'ctx, 'this>(
 
                 (Some(stack_ptr), tag_val)
             } else {
-                let tag_val =
-                    entry.extract_value(context, location, entry.argument(0)?.into(), tag_ty, 0)?;
-
-                (None, tag_val)
+                // C-style enums are represented by their tag alone.
+                (None, entry.argument(0)?.into())
             };
 
             let default_block = helper.append_block(Block::new(&[]));
@@ -499,17 +473,13 @@ pub fn build_snapshot_match<'ctx, 'this>(
             )?);
 
             // Default block.
-            {
-                let val = default_block.const_int(context, location, 0, 1)?;
-
-                default_block.append_operation(cf::assert(
-                    context,
-                    val,
-                    "Invalid enum tag.",
-                    location,
-                ));
-                default_block.append_operation(llvm::unreachable(location));
-            }
+            helper.build_trap(
+                context,
+                metadata,
+                default_block,
+                TrapCode::InvalidEnumTag,
+                location,
+            )?;
 
             // Enum variants.
             for (i, (block, (payload_ty, _))) in
@@ -634,12 +604,220 @@ mod test {
         );
     }
 
+    #[test]
+    fn enum_init_felt_payload() {
+        let program = load_cairo! {
+            fn make_ok(x: felt252) -> Result<felt252, felt252> nopanic {
+                Result::Ok(x)
+            }
+
+            fn make_err(x: felt252) -> Result<felt252, felt252> nopanic {
+                Result::Err(x)
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "make_ok",
+            &[Felt::from(-1).into()],
+            jit_enum!(0, Felt::from(-1).into()),
+        );
+        run_program_assert_output(
+            &program,
+            "make_err",
+            &[Felt::from(1234).into()],
+            jit_enum!(1, Felt::from(1234).into()),
+        );
+    }
+
     #[test]
     fn enum_match() {
         run_program_assert_output(&ENUM_MATCH, "match_a", &[], Felt::from(5).into());
         run_program_assert_output(&ENUM_MATCH, "match_b", &[], 5u8.into());
     }
 
+    #[test]
+    fn enum_match_option() {
+        let program = load_cairo! {
+            fn make_option(x: felt252) -> Option<felt252> {
+                if x == 0 {
+                    Option::None
+                } else {
+                    Option::Some(x)
+                }
+            }
+
+            fn run_test(x: felt252) -> felt252 {
+                match make_option(x) {
+                    Option::Some(x) => x,
+                    Option::None => 42,
+                }
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(7).into()],
+            Felt::from(7).into(),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(0).into()],
+            Felt::from(42).into(),
+        );
+        run_program_assert_output(
+            &program,
+            "make_option",
+            &[Felt::from(7).into()],
+            jit_enum!(0, Felt::from(7).into()),
+        );
+        run_program_assert_output(
+            &program,
+            "make_option",
+            &[Felt::from(0).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
+    #[test]
+    fn enum_snapshot_match() {
+        let program = load_cairo! {
+            #[derive(Drop)]
+            enum MyEnum {
+                A: felt252,
+                B: u8,
+            }
+
+            fn run_test(x: u8) -> u8 {
+                let value = MyEnum::B(x);
+                match @value {
+                    MyEnum::A(_) => 0_u8,
+                    MyEnum::B(x) => *x,
+                }
+            }
+        };
+
+        run_program_assert_output(&program, "run_test", &[5u8.into()], 5u8.into());
+    }
+
+    #[test]
+    fn enum_snapshot_match_mixed_payloads() {
+        let program = load_cairo! {
+            #[derive(Drop)]
+            enum MyEnum {
+                A: u8,
+                B: felt252,
+                C: u64,
+                D: (),
+            }
+
+            fn describe(value: @MyEnum) -> felt252 {
+                match value {
+                    MyEnum::A(x) => (*x).into(),
+                    MyEnum::B(x) => *x,
+                    MyEnum::C(x) => (*x).into(),
+                    MyEnum::D(_) => 42,
+                }
+            }
+
+            fn run_test() -> (felt252, felt252, felt252, felt252) {
+                let a = MyEnum::A(12);
+                let b = MyEnum::B(-1);
+                let c = MyEnum::C(1234567890123456);
+                let d = MyEnum::D(());
+                (describe(@a), describe(@b), describe(@c), describe(@d))
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_struct!(
+                Felt::from(12).into(),
+                Felt::from(-1).into(),
+                Felt::from(1234567890123456u64).into(),
+                Felt::from(42).into(),
+            ),
+        );
+    }
+
+    #[test]
+    fn enum_match_c_style() {
+        let program = load_cairo! {
+            #[derive(Drop)]
+            enum Color {
+                Red: (),
+                Green: (),
+                Blue: (),
+            }
+
+            fn make_color(x: u8) -> Color {
+                if x == 0 {
+                    Color::Red
+                } else if x == 1 {
+                    Color::Green
+                } else {
+                    Color::Blue
+                }
+            }
+
+            fn run_test(x: u8) -> felt252 {
+                match make_color(x) {
+                    Color::Red => 10,
+                    Color::Green => 20,
+                    Color::Blue => 30,
+                }
+            }
+        };
+
+        run_program_assert_output(&program, "run_test", &[0u8.into()], Felt::from(10).into());
+        run_program_assert_output(&program, "run_test", &[1u8.into()], Felt::from(20).into());
+        run_program_assert_output(&program, "run_test", &[2u8.into()], Felt::from(30).into());
+        run_program_assert_output(
+            &program,
+            "make_color",
+            &[1u8.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
+    #[test]
+    fn enum_from_bounded_int() {
+        // Matching over consecutive values starting at zero lowers through
+        // `enum_from_bounded_int`.
+        let program = load_cairo! {
+            fn run_test(x: u8) -> felt252 {
+                match x {
+                    0 => 10,
+                    1 => 20,
+                    2 => 30,
+                    _ => 40,
+                }
+            }
+        };
+        let libfunc_names = program
+            .1
+            .libfunc_declarations
+            .iter()
+            .map(|decl| decl.long_id.generic_id.0.as_str())
+            .collect::<Vec<_>>();
+        assert!(libfunc_names.contains(&"enum_from_bounded_int"));
+
+        run_program_assert_output(&program, "run_test", &[0u8.into()], Felt::from(10).into());
+        run_program_assert_output(&program, "run_test", &[1u8.into()], Felt::from(20).into());
+        run_program_assert_output(&program, "run_test", &[2u8.into()], Felt::from(30).into());
+        run_program_assert_output(&program, "run_test", &[3u8.into()], Felt::from(40).into());
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[u8::MAX.into()],
+            Felt::from(40).into(),
+        );
+    }
+
     #[test]
     fn compile_enum_match_without_variants() {
         let (_, program) = load_cairo! {
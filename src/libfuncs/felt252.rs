@@ -27,7 +27,8 @@ use melior::{
     ir::{r#type::IntegerType, Block, Location, Value, ValueLike},
     Context,
 };
-use num_bigint::{Sign, ToBigInt};
+use num_bigint::ToBigInt;
+use num_traits::Euclid;
 use starknet_types_core::felt::Felt;
 
 /// Select and call the correct libfunc builder function from the selector.
@@ -89,18 +90,11 @@ pub fn build_binary_operation<'ctx, 'this>(
             entry.argument(1)?.into(),
         ),
         Felt252BinaryOperationConcrete::WithConst(operation) => {
-            let value = match operation.c.sign() {
-                Sign::Minus => {
-                    let prime = metadata
-                        .get::<PrimeModuloMeta<Felt>>()
-                        .ok_or(Error::MissingMetadata)?
-                        .prime();
-                    (&operation.c + prime.to_bigint().expect("always is Some"))
-                        .to_biguint()
-                        .expect("always positive")
-                }
-                _ => operation.c.to_biguint().expect("sign already checked"),
-            };
+            let value = operation
+                .c
+                .rem_euclid(&prime.to_bigint().expect("always is Some"))
+                .to_biguint()
+                .expect("always positive");
 
             // TODO: Ensure that the constant is on the correct side of the operation.
             let rhs = entry.const_int_from_type(context, location, value, felt252_ty)?;
@@ -338,18 +332,19 @@ pub fn build_const<'ctx, 'this>(
     metadata: &mut MetadataStorage,
     info: &Felt252ConstConcreteLibfunc,
 ) -> Result<()> {
-    let value = match info.c.sign() {
-        Sign::Minus => {
-            let prime = metadata
+    // Constants may be negative or exceed the prime, so they have to be reduced into `[0, PRIME)`.
+    let value = info
+        .c
+        .rem_euclid(
+            &metadata
                 .get::<PrimeModuloMeta<Felt>>()
                 .ok_or(Error::MissingMetadata)?
-                .prime();
-            (&info.c + prime.to_bigint().expect("always is Some"))
-                .to_biguint()
-                .expect("always is positive")
-        }
-        _ => info.c.to_biguint().expect("sign already checked"),
-    };
+                .prime()
+                .to_bigint()
+                .expect("always is Some"),
+        )
+        .to_biguint()
+        .expect("always is positive");
 
     let felt252_ty = registry.build_type(
         context,
@@ -387,11 +382,14 @@ pub fn build_is_zero<'ctx, 'this>(
 #[cfg(test)]
 pub mod test {
     use crate::{
-        utils::test::{load_cairo, run_program, run_program_assert_output},
+        types::felt252::PRIME,
+        utils::test::{jit_enum, jit_struct, load_cairo, run_program, run_program_assert_output},
         values::JitValue,
     };
-    use cairo_lang_sierra::program::Program;
+    use cairo_lang_sierra::{program::Program, ProgramParser};
     use lazy_static::lazy_static;
+    use num_bigint::BigUint;
+    use starknet_types_core::felt::Felt;
 
     lazy_static! {
         static ref FELT252_ADD: (String, Program) = load_cairo! {
@@ -407,19 +405,49 @@ pub mod test {
             }
         };
 
+        static ref FELT252_ADD_CANONICAL: (String, Program) = load_cairo! {
+            fn run_test(lhs: felt252, rhs: felt252) -> (felt252, bool) {
+                let result = lhs + rhs;
+                (result, result == -2)
+            }
+        };
+
         static ref FELT252_SUB: (String, Program) = load_cairo! {
             fn run_test(lhs: felt252, rhs: felt252) -> felt252 {
                 lhs - rhs
             }
         };
 
+        static ref FELT252_SUB_CHAIN: (String, Program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252, c: felt252) -> felt252 {
+                (a - b - c) * (b - a)
+            }
+        };
+
         static ref FELT252_MUL: (String, Program) = load_cairo! {
             fn run_test(lhs: felt252, rhs: felt252) -> felt252 {
                 lhs * rhs
             }
         };
 
-        // TODO: Add test program for `felt252_div`.
+        static ref FELT252_DIV: (String, Program) = load_cairo! {
+            use zeroable::IsZeroResult;
+
+            fn run_test(lhs: felt252, rhs: felt252) -> felt252 {
+                felt252_div(lhs, felt_to_nonzero(rhs))
+            }
+
+            fn run_round_trip(lhs: felt252, rhs: felt252) -> felt252 {
+                felt252_div(lhs, felt_to_nonzero(rhs)) * rhs
+            }
+
+            fn felt_to_nonzero(value: felt252) -> NonZero<felt252> {
+                match felt252_is_zero(value) {
+                    IsZeroResult::Zero(()) => panic(ArrayTrait::new()),
+                    IsZeroResult::NonZero(x) => x,
+                }
+            }
+        };
 
         // TODO: Add test program for `felt252_add_const`.
         // TODO: Add test program for `felt252_sub_const`.
@@ -440,6 +468,21 @@ pub mod test {
                 }
             }
         };
+
+        static ref FELT252_IS_ZERO_FACTORIAL: (String, Program) = load_cairo! {
+            fn run_test(n: felt252) -> felt252 {
+                match n {
+                    0 => 1,
+                    _ => n * run_test(n - 1),
+                }
+            }
+        };
+
+        static ref FELT252_EQ: (String, Program) = load_cairo! {
+            fn run_test(lhs: felt252, rhs: felt252) -> bool {
+                lhs == rhs
+            }
+        };
     }
 
     #[test]
@@ -526,6 +569,25 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn felt252_add_canonical() {
+        let r =
+            |lhs, rhs| run_program(&FELT252_ADD_CANONICAL, "run_test", &[lhs, rhs]).return_value;
+
+        assert_eq!(
+            r(JitValue::felt_str("-1"), JitValue::felt_str("-1")),
+            jit_struct!(JitValue::felt_str("-2"), jit_enum!(1, jit_struct!()))
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-1"), JitValue::felt_str("0")),
+            jit_struct!(JitValue::felt_str("-1"), jit_enum!(0, jit_struct!()))
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-3"), JitValue::felt_str("1")),
+            jit_struct!(JitValue::felt_str("-2"), jit_enum!(1, jit_struct!()))
+        );
+    }
+
     #[test]
     fn felt252_sub() {
         let r = |lhs, rhs| run_program(&FELT252_SUB, "run_test", &[lhs, rhs]).return_value;
@@ -599,6 +661,64 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn felt252_sub_negative() {
+        let r = |lhs, rhs| run_program(&FELT252_SUB, "run_test", &[lhs, rhs]).return_value;
+
+        assert_eq!(
+            r(JitValue::felt_str("0"), JitValue::felt_str("1")),
+            JitValue::felt_str("-1")
+        );
+        assert_eq!(
+            r(JitValue::felt_str("3"), JitValue::felt_str("5")),
+            JitValue::felt_str("-2")
+        );
+        assert_eq!(
+            r(
+                JitValue::felt_str("5"),
+                JitValue::felt_str(
+                    "1809251394333065553493296640760748560207343510400633813116524750123642650624"
+                )
+            ),
+            JitValue::felt_str(
+                "1809251394333065660204026142334321545415763704930962886856567306012229369862"
+            )
+        );
+    }
+
+    #[test]
+    fn felt252_sub_chain() {
+        let r = |a, b, c| run_program(&FELT252_SUB_CHAIN, "run_test", &[a, b, c]).return_value;
+
+        // (3 - 5 - 7) * (5 - 3) = -18
+        assert_eq!(
+            r(
+                JitValue::felt_str("3"),
+                JitValue::felt_str("5"),
+                JitValue::felt_str("7")
+            ),
+            JitValue::felt_str("-18")
+        );
+        // (0 - 1 - 1) * (1 - 0) = -2
+        assert_eq!(
+            r(
+                JitValue::felt_str("0"),
+                JitValue::felt_str("1"),
+                JitValue::felt_str("1")
+            ),
+            JitValue::felt_str("-2")
+        );
+        // (5 - 2 - 9) * (2 - 5) = 18
+        assert_eq!(
+            r(
+                JitValue::felt_str("5"),
+                JitValue::felt_str("2"),
+                JitValue::felt_str("9")
+            ),
+            JitValue::felt_str("18")
+        );
+    }
+
     #[test]
     fn felt252_mul() {
         let r = |lhs, rhs| run_program(&FELT252_MUL, "run_test", &[lhs, rhs]).return_value;
@@ -672,6 +792,97 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn felt252_mul_large() {
+        #[track_caller]
+        fn check(lhs: &str, rhs: &str) {
+            let expected =
+                (lhs.parse::<BigUint>().unwrap() * rhs.parse::<BigUint>().unwrap()) % &*PRIME;
+
+            assert_eq!(
+                run_program(
+                    &FELT252_MUL,
+                    "run_test",
+                    &[JitValue::felt_str(lhs), JitValue::felt_str(rhs)],
+                )
+                .return_value,
+                JitValue::Felt252(Felt::from(&expected)),
+            );
+        }
+
+        let prime_minus_one = (&*PRIME - 1u8).to_string();
+
+        check(&prime_minus_one, &prime_minus_one);
+        check(&prime_minus_one, "2");
+        check(
+            "1809251394333065553493296640760748560207343510400633813116524750123642650623",
+            "1809251394333065553493296640760748560207343510400633813116524750123642650625",
+        );
+        check(
+            "3141592653589793238462643383279502884197169399375105820974944592307816406286",
+            "2718281828459045235360287471352662497757247093699959574966967627724076630353",
+        );
+        check(
+            "1234567890123456789012345678901234567890123456789012345678901234567890123456",
+            "3618502788666131213697322783095070105623107215331596699973092056135872020000",
+        );
+    }
+
+    #[test]
+    fn felt252_div() {
+        let r = |lhs, rhs| run_program(&FELT252_DIV, "run_test", &[lhs, rhs]).return_value;
+
+        assert_eq!(
+            r(JitValue::felt_str("0"), JitValue::felt_str("1")),
+            JitValue::felt_str("0")
+        );
+        assert_eq!(
+            r(JitValue::felt_str("6"), JitValue::felt_str("3")),
+            JitValue::felt_str("2")
+        );
+        assert_eq!(
+            r(JitValue::felt_str("5"), JitValue::felt_str("2")),
+            JitValue::felt_str(
+                "1809251394333065606848661391547535052811553607665798349986546028067936010243"
+            )
+        );
+        assert_eq!(
+            r(JitValue::felt_str("1"), JitValue::felt_str("-2")),
+            JitValue::felt_str(
+                "1809251394333065606848661391547535052811553607665798349986546028067936010240"
+            )
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-1"), JitValue::felt_str("-2")),
+            JitValue::felt_str(
+                "1809251394333065606848661391547535052811553607665798349986546028067936010241"
+            )
+        );
+        assert_eq!(
+            r(JitValue::felt_str("7"), JitValue::felt_str("-1")),
+            JitValue::felt_str("-7")
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-1"), JitValue::felt_str("-1")),
+            JitValue::felt_str("1")
+        );
+
+        let r = |lhs, rhs| run_program(&FELT252_DIV, "run_round_trip", &[lhs, rhs]).return_value;
+
+        assert_eq!(
+            r(JitValue::felt_str("5"), JitValue::felt_str("-3")),
+            JitValue::felt_str("5")
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-5"), JitValue::felt_str("-1")),
+            JitValue::felt_str("-5")
+        );
+        assert_eq!(
+            r(JitValue::felt_str("12345"), JitValue::felt_str("-12345")),
+            JitValue::felt_str("12345")
+        );
+    }
+
     #[test]
     fn felt252_const() {
         assert_eq!(
@@ -688,6 +899,46 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn felt252_const_reduced() {
+        // Cairo rejects out-of-range literals, so the constants are declared in Sierra directly.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+                    type Tuple = Struct<ut@Tuple, felt252, felt252, felt252, felt252>;
+
+                    libfunc const_neg_one = felt252_const<-1>;
+                    libfunc const_prime = felt252_const<3618502788666131213697322783095070105623107215331596699973092056135872020481>;
+                    libfunc const_prime_plus_five = felt252_const<3618502788666131213697322783095070105623107215331596699973092056135872020486>;
+                    libfunc const_300_bits = felt252_const<2037035976334486086268445688409378161051468393665936250636140449354381299763336706183397383>;
+                    libfunc construct_tuple = struct_construct<Tuple>;
+
+                    const_neg_one() -> ([0]);
+                    const_prime() -> ([1]);
+                    const_prime_plus_five() -> ([2]);
+                    const_300_bits() -> ([3]);
+                    construct_tuple([0], [1], [2], [3]) -> ([4]);
+                    return([4]);
+
+                    program::program::run_test@0() -> (Tuple);
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            run_program(&("program".to_string(), program), "run_test", &[]).return_value,
+            jit_struct!(
+                JitValue::felt_str("-1"),
+                JitValue::felt_str("0"),
+                JitValue::felt_str("5"),
+                JitValue::felt_str(
+                    "3558429988463666146491490668069810876084972762837825655396956882341969526792"
+                ),
+            )
+        );
+    }
+
     #[test]
     fn felt252_is_zero() {
         let r = |x| run_program(&FELT252_IS_ZERO, "run_test", &[x]).return_value;
@@ -697,4 +948,75 @@ pub mod test {
         assert_eq!(r(JitValue::felt_str("-2")), JitValue::felt_str("0"));
         assert_eq!(r(JitValue::felt_str("-1")), JitValue::felt_str("0"));
     }
+
+    #[test]
+    fn felt252_is_zero_branch_targets() {
+        // The zero branch falls through while the non-zero branch jumps forward, forwarding its
+        // result to the target block.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+                    type NonZeroFelt252 = NonZero<felt252>;
+
+                    libfunc felt252_is_zero = felt252_is_zero;
+                    libfunc branch_align = branch_align;
+                    libfunc unwrap_non_zero = unwrap_non_zero<felt252>;
+                    libfunc felt252_add = felt252_add;
+                    libfunc const_seven = felt252_const<7>;
+
+                    felt252_is_zero([0]) { fallthrough() 4([1]) };
+                    branch_align() -> ();
+                    const_seven() -> ([2]);
+                    return([2]);
+                    branch_align() -> ();
+                    unwrap_non_zero([1]) -> ([3]);
+                    felt252_add([3], [3]) -> ([4]);
+                    return([4]);
+
+                    program::program::run_test@0([0]: felt252) -> (felt252);
+                "#,
+            )
+            .unwrap();
+        let program = ("program".to_string(), program);
+
+        let r = |x| run_program(&program, "run_test", &[x]).return_value;
+
+        assert_eq!(r(JitValue::felt_str("0")), JitValue::felt_str("7"));
+        assert_eq!(r(JitValue::felt_str("1")), JitValue::felt_str("2"));
+        assert_eq!(r(JitValue::felt_str("21")), JitValue::felt_str("42"));
+        assert_eq!(r(JitValue::felt_str("-1")), JitValue::felt_str("-2"));
+    }
+
+    #[test]
+    fn felt252_is_zero_factorial() {
+        let r = |x| run_program(&FELT252_IS_ZERO_FACTORIAL, "run_test", &[x]).return_value;
+
+        assert_eq!(r(JitValue::felt_str("0")), JitValue::felt_str("1"));
+        assert_eq!(r(JitValue::felt_str("1")), JitValue::felt_str("1"));
+        assert_eq!(r(JitValue::felt_str("5")), JitValue::felt_str("120"));
+        assert_eq!(r(JitValue::felt_str("10")), JitValue::felt_str("3628800"));
+    }
+
+    #[test]
+    fn felt252_eq() {
+        let r = |lhs, rhs| run_program(&FELT252_EQ, "run_test", &[lhs, rhs]).return_value;
+
+        assert_eq!(
+            r(JitValue::felt_str("0"), JitValue::felt_str("0")),
+            jit_enum!(1, jit_struct!())
+        );
+        assert_eq!(
+            r(JitValue::felt_str("1"), JitValue::felt_str("0")),
+            jit_enum!(0, jit_struct!())
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-1"), JitValue::felt_str("-1")),
+            jit_enum!(1, jit_struct!())
+        );
+        assert_eq!(
+            r(JitValue::felt_str("-1"), JitValue::felt_str("1")),
+            jit_enum!(0, jit_struct!())
+        );
+    }
 }
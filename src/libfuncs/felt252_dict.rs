@@ -112,7 +112,9 @@ pub fn build_squash<'ctx, 'this>(
 #[cfg(test)]
 mod test {
     use crate::{
-        utils::test::{jit_dict, jit_enum, jit_struct, load_cairo, run_program_assert_output},
+        utils::test::{
+            jit_dict, jit_enum, jit_struct, load_cairo, run_program, run_program_assert_output,
+        },
         values::JitValue,
     };
 
@@ -130,6 +132,89 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], jit_struct!());
     }
 
+    #[test]
+    fn run_dict_new_squash() {
+        let program = load_cairo!(
+            use traits::Default;
+            use dict::Felt252DictTrait;
+
+            fn run_test() -> SquashedFelt252Dict<u32> {
+                let dict: Felt252Dict<u32> = Default::default();
+                dict.squash()
+            }
+        );
+
+        run_program_assert_output(&program, "run_test", &[], jit_dict!());
+    }
+
+    #[test]
+    fn run_dict_squash() {
+        let program = load_cairo!(
+            use traits::Default;
+            use dict::Felt252DictTrait;
+
+            fn run_test() -> SquashedFelt252Dict<u32> {
+                let mut dict: Felt252Dict<u32> = Default::default();
+                dict.insert(1, 2_u32);
+                dict.insert(2, 3_u32);
+                dict.insert(1, 4_u32);
+                dict.squash()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_dict!(
+                1 => 4u32,
+                2 => 3u32,
+            ),
+        );
+    }
+
+    #[test]
+    fn run_dict_squash_drop() {
+        let program = load_cairo!(
+            use traits::Default;
+            use dict::Felt252DictTrait;
+
+            fn run_test() -> u32 {
+                let mut dict: Felt252Dict<u32> = Default::default();
+                dict.insert(1, 2_u32);
+                let value = dict.get(1);
+                let _squashed = dict.squash();
+                value
+            }
+        );
+
+        run_program_assert_output(&program, "run_test", &[], 2u32.into());
+    }
+
+    #[test]
+    fn run_dict_segment_arena_counter() {
+        let program = load_cairo!(
+            use traits::Default;
+            use dict::Felt252DictTrait;
+
+            fn run_test() -> u32 {
+                let mut lhs: Felt252Dict<u32> = Default::default();
+                let mut rhs: Felt252Dict<u32> = Default::default();
+                lhs.insert(1, 2_u32);
+                rhs.insert(1, 3_u32);
+                let value = lhs.get(1) + rhs.get(1);
+                let _lhs = lhs.squash();
+                let _rhs = rhs.squash();
+                value
+            }
+        );
+
+        let result = run_program(&program, "run_test", &[]);
+        assert_eq!(result.return_value, jit_enum!(0, jit_struct!(5u32.into())));
+        // Both allocations and both squashes are accounted for.
+        assert_eq!(result.builtin_stats.segment_arena, 4);
+    }
+
     #[test]
     fn run_dict_insert() {
         let program = load_cairo!(
@@ -275,7 +275,9 @@ pub fn build_finalize<'ctx, 'this>(
 
 #[cfg(test)]
 mod test {
-    use crate::utils::test::{jit_dict, load_cairo, run_program_assert_output};
+    use crate::utils::test::{
+        jit_dict, jit_enum, jit_struct, load_cairo, run_program_assert_output,
+    };
 
     #[test]
     fn run_dict_insert() {
@@ -363,4 +365,42 @@ mod test {
 
         run_program_assert_output(&program, "run_test", &[], 1345432_u32.into());
     }
+
+    #[test]
+    fn run_dict_overwrite_and_defaults() {
+        let program = load_cairo!(
+            use traits::Default;
+            use dict::Felt252DictTrait;
+            use nullable::{match_nullable, FromNullableResult};
+
+            fn run_test() -> (u32, u32, u32, u32, bool) {
+                let mut dict: Felt252Dict<u32> = Default::default();
+                dict.insert(1, 10_u32);
+                dict.insert(2, 20_u32);
+                dict.insert(3, 30_u32);
+                dict.insert(2, 25_u32);
+
+                let mut nullables: Felt252Dict<Nullable<u32>> = Default::default();
+                let is_null = match match_nullable(nullables.get(7)) {
+                    FromNullableResult::Null => true,
+                    FromNullableResult::NotNull(_) => false,
+                };
+
+                (dict.get(1), dict.get(2), dict.get(3), dict.get(4), is_null)
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_struct!(
+                10u32.into(),
+                25u32.into(),
+                30u32.into(),
+                0u32.into(),
+                jit_enum!(1, jit_struct!()),
+            ),
+        );
+    }
 }
@@ -7,7 +7,10 @@ use super::LibfuncHelper;
 use crate::{
     block_ext::BlockExt,
     error::Result,
-    metadata::{tail_recursion::TailRecursionMeta, MetadataStorage},
+    metadata::{
+        runtime_bindings::RuntimeBindingsMeta, tail_recursion::TailRecursionMeta,
+        trap_analysis::TrapAnalysisMeta, MetadataStorage,
+    },
     types::TypeBuilder,
     utils::generate_function_name,
 };
@@ -19,7 +22,10 @@ use cairo_lang_sierra::{
     program_registry::ProgramRegistry,
 };
 use melior::{
-    dialect::{cf, func, index, llvm, memref},
+    dialect::{
+        arith::{self, CmpiPredicate},
+        cf, func, index, llvm, memref,
+    },
     ir::{
         attribute::{DenseI32ArrayAttribute, FlatSymbolRefAttribute, IntegerAttribute},
         r#type::IntegerType,
@@ -276,7 +282,48 @@ pub fn build<'ctx, 'this>(
             }
         }
 
-        entry.append_operation(helper.br(0, &results, location));
+        // Return straight away if the callee trapped, so that no more user code runs. Checking
+        // requires a call into the runtime, so it's skipped for callees which can't trap.
+        let callee_may_trap = metadata
+            .get::<TrapAnalysisMeta>()
+            .map_or(true, |x| x.may_trap(&info.function.id));
+        match metadata
+            .get_mut::<RuntimeBindingsMeta>()
+            .filter(|_| callee_may_trap)
+        {
+            Some(runtime_bindings) => {
+                let trap_code = runtime_bindings
+                    .peek_trap(context, helper, entry, location)?
+                    .result(0)?
+                    .into();
+                let k0 = entry.const_int(context, location, 0, 64)?;
+                let has_trapped = entry.append_op_result(arith::cmpi(
+                    context,
+                    CmpiPredicate::Ne,
+                    trap_code,
+                    k0,
+                    location,
+                ))?;
+
+                let trap_block = helper.append_block(Block::new(&[]));
+                let cont_block = helper.append_block(Block::new(&[]));
+                entry.append_operation(cf::cond_br(
+                    context,
+                    has_trapped,
+                    trap_block,
+                    cont_block,
+                    &[],
+                    &[],
+                    location,
+                ));
+
+                helper.build_poisoned_return(trap_block, location)?;
+                cont_block.append_operation(helper.br(0, &results, location));
+            }
+            None => {
+                entry.append_operation(helper.br(0, &results, location));
+            }
+        }
     }
 
     if let Some(tailrec_meta) = tailrec_meta {
@@ -285,3 +332,168 @@ pub fn build<'ctx, 'this>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        context::NativeContext,
+        utils::test::{jit_enum, jit_struct, load_cairo, run_program, run_program_assert_output},
+        values::JitValue,
+    };
+    use cairo_lang_sierra::{
+        extensions::core::{CoreLibfunc, CoreType},
+        program::Program,
+        program_registry::ProgramRegistry,
+        ProgramParser,
+    };
+
+    #[test]
+    fn run_fib_helper() {
+        let program = load_cairo! {
+            fn fib(n: u32) -> u32 {
+                if n < 2 {
+                    n
+                } else {
+                    fib(n - 1) + fib(n - 2)
+                }
+            }
+
+            fn run_test() -> u32 {
+                fib(15)
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_enum!(0, jit_struct!(610u32.into())),
+        );
+    }
+
+    #[test]
+    fn run_mutual_recursion() {
+        let program = load_cairo! {
+            fn is_even(n: u32) -> bool {
+                if n == 0 {
+                    true
+                } else {
+                    is_odd(n - 1)
+                }
+            }
+
+            fn is_odd(n: u32) -> bool {
+                if n == 0 {
+                    false
+                } else {
+                    is_even(n - 1)
+                }
+            }
+
+            fn run_test() -> (bool, bool) {
+                (is_even(10), is_odd(7))
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(
+                    jit_enum!(1, jit_struct!()),
+                    jit_enum!(1, jit_struct!()),
+                ))
+            ),
+        );
+    }
+
+    #[test]
+    fn run_forward_declared_callee() {
+        // The callee is declared after its caller, so its signature must be known beforehand.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+
+                    libfunc call_double = function_call<user@program::program::double>;
+                    libfunc dup_felt252 = dup<felt252>;
+                    libfunc felt252_add = felt252_add;
+
+                    call_double([0]) -> ([1]);
+                    return([1]);
+                    dup_felt252([0]) -> ([0], [1]);
+                    felt252_add([0], [1]) -> ([2]);
+                    return([2]);
+
+                    program::program::run_test@0([0]: felt252) -> (felt252);
+                    program::program::double@2([0]: felt252) -> (felt252);
+                "#,
+            )
+            .unwrap();
+
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+        for func in &program.funcs {
+            let signature = &registry.get_function(&func.id).unwrap().signature;
+            assert_eq!(signature.param_types.len(), 1);
+            assert_eq!(signature.ret_types.len(), 1);
+        }
+
+        assert_eq!(
+            run_program(
+                &("program".to_string(), program),
+                "run_test",
+                &[JitValue::felt_str("21")],
+            )
+            .return_value,
+            JitValue::felt_str("42"),
+        );
+    }
+
+    #[test]
+    fn trap_check_only_after_trapping_callees() {
+        let compile = |program: &(String, Program)| {
+            NativeContext::new()
+                .compile(&program.1, None)
+                .unwrap()
+                .module()
+                .as_operation()
+                .to_string()
+        };
+
+        // A callee which only adds felts can't trap, so calling it doesn't check for traps.
+        let program = load_cairo! {
+            #[inline(never)]
+            fn add(a: felt252, b: felt252) -> felt252 {
+                a + b
+            }
+
+            fn run_test(a: felt252) -> felt252 {
+                add(a, 1)
+            }
+        };
+        assert!(!compile(&program).contains("cairo_native__peek_trap"));
+
+        // Matching an enum traps on invalid tags, and so do its callers.
+        let program = load_cairo! {
+            #[inline(never)]
+            fn unwrap_or_zero(x: Option<felt252>) -> felt252 {
+                match x {
+                    Option::Some(x) => x,
+                    Option::None => 0,
+                }
+            }
+
+            #[inline(never)]
+            fn add_or_zero(a: Option<felt252>, b: felt252) -> felt252 {
+                unwrap_or_zero(a) + b
+            }
+
+            fn run_test(a: felt252) -> felt252 {
+                add_or_zero(Option::Some(a), 1)
+            }
+        };
+        assert!(compile(&program).contains("cairo_native__peek_trap"));
+    }
+}
@@ -3,7 +3,7 @@
 use super::LibfuncHelper;
 use crate::{
     block_ext::BlockExt,
-    error::Result,
+    error::{Error, Result},
     metadata::{gas::GasCost, MetadataStorage},
     utils::ProgramRegistryExt,
 };
@@ -35,11 +35,13 @@ pub fn build<'ctx, 'this>(
     metadata: &mut MetadataStorage,
     selector: &GasConcreteLibfunc,
 ) -> Result<()> {
+    check_supported(selector)?;
+
     match selector {
         GasConcreteLibfunc::WithdrawGas(info) => {
             build_withdraw_gas(context, registry, entry, location, helper, metadata, info)
         }
-        GasConcreteLibfunc::RedepositGas(_) => todo!("implement redeposit gas libfunc"),
+        GasConcreteLibfunc::RedepositGas(_) => unreachable!("rejected by check_supported"),
         GasConcreteLibfunc::GetAvailableGas(info) => {
             build_get_available_gas(context, registry, entry, location, helper, metadata, info)
         }
@@ -52,6 +54,16 @@ pub fn build<'ctx, 'this>(
     }
 }
 
+/// Return the error [`build`] fails with when it can't generate the selected libfunc.
+pub fn check_supported(selector: &GasConcreteLibfunc) -> Result<()> {
+    match selector {
+        GasConcreteLibfunc::RedepositGas(_) => {
+            Err(Error::UnsupportedLibfunc("redeposit_gas".to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Generate MLIR operations for the `get_builtin_costs` libfunc.
 pub fn build_get_available_gas<'ctx, 'this>(
     _context: &'ctx Context,
@@ -184,7 +196,18 @@ pub fn build_get_builtin_costs<'ctx, 'this>(
 
 #[cfg(test)]
 mod test {
-    use crate::utils::test::{load_cairo, run_program};
+    use crate::{
+        context::NativeContext,
+        executor::JitNativeExecutor,
+        metadata::gas::{GasMetadata, MetadataComputationConfig},
+        utils::{
+            find_function_id,
+            test::{jit_enum, jit_struct, load_cairo, run_program},
+        },
+        values::JitValue,
+        OptLevel,
+    };
+    use starknet_types_core::felt::Felt;
 
     #[test]
     fn run_withdraw_gas() {
@@ -219,4 +242,92 @@ mod test {
             Some(340282366920938463463374607431768204835),
         );
     }
+
+    #[test]
+    fn run_remaining_gas() {
+        #[rustfmt::skip]
+        let program = load_cairo!(
+            use gas::withdraw_gas;
+
+            fn run_loop(n: felt252) -> felt252 {
+                let mut i = n;
+                loop {
+                    if i == 0 {
+                        break;
+                    }
+
+                    match withdraw_gas() {
+                        Option::Some(()) => {},
+                        Option::None(()) => {
+                            break;
+                        }
+                    };
+                    i = i - 1;
+                };
+                i
+            }
+
+            fn run_once() -> felt252 {
+                match withdraw_gas() {
+                    Option::Some(()) => 1,
+                    Option::None(()) => 0,
+                }
+            }
+
+            fn run_without_gas(x: felt252) -> felt252 {
+                x
+            }
+        );
+        let function_id =
+            |name: &str| find_function_id(&program.1, &format!("{0}::{0}::{name}", program.0));
+
+        let context = NativeContext::new();
+        let module = context.compile(&program.1, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+        // Every iteration of the loop consumes the same amount of gas.
+        let consumed_gas = |n: u64| {
+            let result = executor
+                .invoke_dynamic(
+                    function_id("run_loop"),
+                    &[JitValue::Felt252(n.into())],
+                    Some(u128::MAX),
+                )
+                .unwrap();
+            assert_eq!(
+                result.return_value,
+                jit_enum!(0, jit_struct!(JitValue::Felt252(Felt::ZERO)))
+            );
+            u128::MAX - result.remaining_gas.unwrap()
+        };
+        let iteration_cost = consumed_gas(2) - consumed_gas(1);
+        assert!(iteration_cost > 0);
+        assert_eq!(consumed_gas(10) - consumed_gas(5), 5 * iteration_cost);
+
+        // Providing only the initial gas makes `withdraw_gas` take the out-of-gas branch.
+        let required_gas = GasMetadata::new(&program.1, Some(MetadataComputationConfig::default()))
+            .unwrap()
+            .initial_required_gas(function_id("run_once"))
+            .unwrap();
+        let result = executor
+            .invoke_dynamic(function_id("run_once"), &[], Some(required_gas))
+            .unwrap();
+        // Unlike the loop, `run_once` can't panic so it isn't wrapped in a `PanicResult`.
+        assert_eq!(result.return_value, JitValue::Felt252(Felt::ZERO));
+
+        let result = executor
+            .invoke_dynamic(function_id("run_once"), &[], Some(u128::MAX))
+            .unwrap();
+        assert_eq!(result.return_value, JitValue::Felt252(Felt::ONE));
+
+        // Functions which don't use gas don't report any remaining gas.
+        let result = executor
+            .invoke_dynamic(
+                function_id("run_without_gas"),
+                &[JitValue::Felt252(Felt::ONE)],
+                Some(u128::MAX),
+            )
+            .unwrap();
+        assert_eq!(result.remaining_gas, None);
+    }
 }
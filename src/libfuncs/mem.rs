@@ -131,3 +131,41 @@ pub fn build_rename<'ctx, 'this>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{context::NativeContext, utils::test::load_cairo};
+
+    #[test]
+    fn memory_libfuncs_are_inlined() {
+        let (_, program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252) -> (felt252, felt252, felt252) {
+                let c = a + b;
+                (a, c, a * c)
+            }
+        };
+
+        let generic_ids = program
+            .libfunc_declarations
+            .iter()
+            .map(|decl| decl.long_id.generic_id.0.as_str())
+            .collect::<Vec<_>>();
+        assert!(generic_ids.contains(&"store_temp"));
+        assert!(generic_ids.contains(&"dup"));
+
+        let native_context = NativeContext::new();
+        let module = native_context.compile(&program, None).unwrap();
+
+        // Only the program's own functions (and runtime declarations) should be emitted.
+        let mlir = module.module().as_operation().to_string();
+        for line in mlir
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| line.starts_with("func.func") || line.starts_with("llvm.func"))
+        {
+            for libfunc in ["store_temp", "rename", "dup"] {
+                assert!(!line.contains(libfunc), "unexpected function: {line}");
+            }
+        }
+    }
+}
@@ -160,4 +160,47 @@ mod test {
             .into(),
         );
     }
+
+    #[test]
+    fn run_pedersen_known_vectors() {
+        let program = load_cairo!(
+            use core::pedersen::pedersen;
+
+            fn run_test(a: felt252, b: felt252) -> felt252 {
+                pedersen(a, b)
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into(), Felt::ZERO.into()],
+            Felt::from_dec_str(
+                "2089986280348253421170679821480865132823066470938446095505822317253594081284",
+            )
+            .unwrap()
+            .into(),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[
+                Felt::from_dec_str(
+                    "1740729136829561885683894917751815192814966525555656371386868611731128807883",
+                )
+                .unwrap()
+                .into(),
+                Felt::from_dec_str(
+                    "919869093895560023824014392670608914007817594969197822578496829435657368346",
+                )
+                .unwrap()
+                .into(),
+            ],
+            Felt::from_dec_str(
+                "1382171651951541052082654537810074813456022260470662576358627909045455537762",
+            )
+            .unwrap()
+            .into(),
+        );
+    }
 }
@@ -249,7 +249,36 @@ pub fn build_to_felt252<'ctx, 'this>(
     )?;
     let value: Value = entry.argument(0)?.into();
 
-    let result = entry.append_op_result(arith::extui(value, felt252_ty, location))?;
+    let prime = entry.const_int_from_type(
+        context,
+        location,
+        metadata
+            .get::<PrimeModuloMeta<Felt>>()
+            .ok_or(Error::MissingMetadata)?
+            .prime()
+            .clone(),
+        felt252_ty,
+    )?;
+    let const_0 = entry.const_int_from_type(context, location, 0, value.r#type())?;
+
+    // Negative values are represented as `PRIME - |value|`.
+    let is_negative = entry.append_op_result(arith::cmpi(
+        context,
+        CmpiPredicate::Slt,
+        value,
+        const_0,
+        location,
+    ))?;
+
+    let value = entry.append_op_result(arith::extsi(value, felt252_ty, location))?;
+    let value_plus_prime = entry.append_op_result(arith::addi(value, prime, location))?;
+
+    let result = entry.append_op_result(arith::select(
+        is_negative,
+        value_plus_prime,
+        value,
+        location,
+    ))?;
 
     entry.append_operation(helper.br(0, &[result], location));
 
@@ -438,6 +467,13 @@ mod test {
     use starknet_types_core::felt::Felt;
 
     lazy_static! {
+        static ref I128_DIFF: (String, Program) = load_cairo! {
+            extern fn i128_diff(lhs: i128, rhs: i128) -> Result<u128, u128> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: i128, rhs: i128) -> Result<u128, u128> {
+                i128_diff(lhs, rhs)
+            }
+        };
         static ref I128_OVERFLOWING_ADD: (String, Program) = load_cairo! {
             fn run_test(lhs: i128, rhs: i128) -> i128 {
                 lhs + rhs
@@ -489,6 +525,46 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], (i128::MAX).into());
     }
 
+    #[test]
+    fn i128_const_negative() {
+        let program = load_cairo!(
+            fn run_test() -> (i128, i128) {
+                (-1_i128, -5_i128)
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_struct!((-1i128).into(), (-5i128).into()),
+        );
+    }
+
+    #[test]
+    fn i128_diff() {
+        #[track_caller]
+        fn run(lhs: i128, rhs: i128) {
+            let result = lhs.wrapping_sub(rhs) as u128;
+
+            run_program_assert_output(
+                &I128_DIFF,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(if lhs >= rhs { 0 } else { 1 }, result.into()),
+            );
+        }
+
+        run(0, 0);
+        run(1, 0);
+        run(0, 1);
+        run(-1, 0);
+        run(0, -1);
+        run(i128::MAX, i128::MIN);
+        run(i128::MIN, i128::MAX);
+        run(i128::MIN, i128::MIN);
+    }
+
     #[test]
     fn i128_to_felt252() {
         let program = load_cairo!(
@@ -526,6 +602,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn i128_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: i128) -> (felt252, Option<i128>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(-1i128).into()],
+            jit_struct!(Felt::MAX.into(), jit_enum!(0, (-1i128).into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[i128::MIN.into()],
+            jit_struct!(Felt::from(i128::MIN).into(), jit_enum!(0, i128::MIN.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[i128::MAX.into()],
+            jit_struct!(Felt::from(i128::MAX).into(), jit_enum!(0, i128::MAX.into())),
+        );
+    }
+
+    #[test]
+    fn i128_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<i128> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(0, (-1i128).into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(i128::MIN).into()],
+            jit_enum!(0, i128::MIN.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(i128::MIN) - Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(i128::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from_dec_str(
+                "1809251394333065553493296640760748560207343510400633813116524750123642650624",
+            )
+            .unwrap()
+            .into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn i128_overflowing_add() {
         #[track_caller]
@@ -556,6 +709,7 @@ mod test {
         }
 
         const MAX: i128 = i128::MAX;
+        const MIN: i128 = i128::MIN;
 
         run(0, 0);
         run(0, 1);
@@ -576,6 +730,17 @@ mod test {
         run(MAX, 1);
         run(MAX, MAX - 1);
         run(MAX, MAX);
+
+        run(-1, -1);
+        run(-1, MIN);
+        run(-1, MAX);
+        run(MIN, -1);
+        run(MIN, 0);
+        run(MIN, 1);
+        run(MIN, MIN);
+        run(MIN, MAX);
+        run(MAX, -1);
+        run(MAX, MIN);
     }
 
     #[test]
@@ -608,6 +773,7 @@ mod test {
         }
 
         const MAX: i128 = i128::MAX;
+        const MIN: i128 = i128::MIN;
 
         run(0, 0);
         run(0, 1);
@@ -628,6 +794,17 @@ mod test {
         run(MAX, 1);
         run(MAX, MAX - 1);
         run(MAX, MAX);
+
+        run(-1, -1);
+        run(-1, MIN);
+        run(-1, MAX);
+        run(MIN, -1);
+        run(MIN, 0);
+        run(MIN, 1);
+        run(MIN, MIN);
+        run(MIN, MAX);
+        run(MAX, -1);
+        run(MAX, MIN);
     }
 
     #[test]
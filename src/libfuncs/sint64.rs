@@ -279,7 +279,36 @@ pub fn build_to_felt252<'ctx, 'this>(
     )?;
     let value: Value = entry.argument(0)?.into();
 
-    let result = entry.append_op_result(arith::extui(value, felt252_ty, location))?;
+    let prime = entry.const_int_from_type(
+        context,
+        location,
+        metadata
+            .get::<PrimeModuloMeta<Felt>>()
+            .ok_or(Error::MissingMetadata)?
+            .prime()
+            .clone(),
+        felt252_ty,
+    )?;
+    let const_0 = entry.const_int_from_type(context, location, 0, value.r#type())?;
+
+    // Negative values are represented as `PRIME - |value|`.
+    let is_negative = entry.append_op_result(arith::cmpi(
+        context,
+        CmpiPredicate::Slt,
+        value,
+        const_0,
+        location,
+    ))?;
+
+    let value = entry.append_op_result(arith::extsi(value, felt252_ty, location))?;
+    let value_plus_prime = entry.append_op_result(arith::addi(value, prime, location))?;
+
+    let result = entry.append_op_result(arith::select(
+        is_negative,
+        value_plus_prime,
+        value,
+        location,
+    ))?;
 
     entry.append_operation(helper.br(0, &[result], location));
 
@@ -562,6 +591,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn i64_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: i64) -> (felt252, Option<i64>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(-1i64).into()],
+            jit_struct!(Felt::MAX.into(), jit_enum!(0, (-1i64).into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[i64::MIN.into()],
+            jit_struct!(Felt::from(i64::MIN).into(), jit_enum!(0, i64::MIN.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[i64::MAX.into()],
+            jit_struct!(Felt::from(i64::MAX).into(), jit_enum!(0, i64::MAX.into())),
+        );
+    }
+
+    #[test]
+    fn i64_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<i64> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(0, (-1i64).into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(i64::MIN).into()],
+            jit_enum!(0, i64::MIN.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(i64::MIN) - Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(i64::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from_dec_str(
+                "1809251394333065553493296640760748560207343510400633813116524750123642650624",
+            )
+            .unwrap()
+            .into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn i64_overflowing_add() {
         #[track_caller]
@@ -810,5 +916,29 @@ mod test {
             &[i64::MAX.into(), i64::MAX.into()],
             (i64::MAX as i128 * i64::MAX as i128).into(),
         );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[i64::MIN.into(), i64::MIN.into()],
+            (i64::MIN as i128 * i64::MIN as i128).into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[i64::MIN.into(), i64::MAX.into()],
+            (i64::MIN as i128 * i64::MAX as i128).into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[i64::MAX.into(), (-1i64).into()],
+            (-(i64::MAX as i128)).into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[(-3i64).into(), 7i64.into()],
+            (-21i128).into(),
+        );
     }
 }
@@ -280,7 +280,36 @@ pub fn build_to_felt252<'ctx, 'this>(
     )?;
     let value: Value = entry.argument(0)?.into();
 
-    let result = entry.append_op_result(arith::extui(value, felt252_ty, location))?;
+    let prime = entry.const_int_from_type(
+        context,
+        location,
+        metadata
+            .get::<PrimeModuloMeta<Felt>>()
+            .ok_or(Error::MissingMetadata)?
+            .prime()
+            .clone(),
+        felt252_ty,
+    )?;
+    let const_0 = entry.const_int_from_type(context, location, 0, value.r#type())?;
+
+    // Negative values are represented as `PRIME - |value|`.
+    let is_negative = entry.append_op_result(arith::cmpi(
+        context,
+        CmpiPredicate::Slt,
+        value,
+        const_0,
+        location,
+    ))?;
+
+    let value = entry.append_op_result(arith::extsi(value, felt252_ty, location))?;
+    let value_plus_prime = entry.append_op_result(arith::addi(value, prime, location))?;
+
+    let result = entry.append_op_result(arith::select(
+        is_negative,
+        value_plus_prime,
+        value,
+        location,
+    ))?;
 
     entry.append_operation(helper.br(0, &[result], location));
 
@@ -470,6 +499,13 @@ mod test {
     use starknet_types_core::felt::Felt;
 
     lazy_static! {
+        static ref I8_DIFF: (String, Program) = load_cairo! {
+            extern fn i8_diff(lhs: i8, rhs: i8) -> Result<u8, u8> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: i8, rhs: i8) -> Result<u8, u8> {
+                i8_diff(lhs, rhs)
+            }
+        };
         static ref I8_OVERFLOWING_ADD: (String, Program) = load_cairo! {
             fn run_test(lhs: i8, rhs: i8) -> i8 {
                 lhs + rhs
@@ -527,6 +563,46 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], (i8::MAX).into());
     }
 
+    #[test]
+    fn i8_const_negative() {
+        let program = load_cairo!(
+            fn run_test() -> (i8, i8) {
+                (-1_i8, -5_i8)
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_struct!((-1i8).into(), (-5i8).into()),
+        );
+    }
+
+    #[test]
+    fn i8_diff() {
+        #[track_caller]
+        fn run(lhs: i8, rhs: i8) {
+            let result = lhs.wrapping_sub(rhs) as u8;
+
+            run_program_assert_output(
+                &I8_DIFF,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(if lhs >= rhs { 0 } else { 1 }, result.into()),
+            );
+        }
+
+        run(0, 0);
+        run(1, 0);
+        run(0, 1);
+        run(-1, 0);
+        run(0, -1);
+        run(i8::MAX, i8::MIN);
+        run(i8::MIN, i8::MAX);
+        run(i8::MIN, i8::MIN);
+    }
+
     #[test]
     fn i8_to_felt252() {
         let program = load_cairo!(
@@ -558,6 +634,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn i8_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: i8) -> (felt252, Option<i8>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(-1i8).into()],
+            jit_struct!(Felt::MAX.into(), jit_enum!(0, (-1i8).into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[i8::MIN.into()],
+            jit_struct!(Felt::from(i8::MIN).into(), jit_enum!(0, i8::MIN.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[i8::MAX.into()],
+            jit_struct!(Felt::from(i8::MAX).into(), jit_enum!(0, i8::MAX.into())),
+        );
+    }
+
+    #[test]
+    fn i8_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<i8> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(0, (-1i8).into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(i8::MIN).into()],
+            jit_enum!(0, i8::MIN.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(i8::MIN) - Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(i8::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from_dec_str(
+                "1809251394333065553493296640760748560207343510400633813116524750123642650624",
+            )
+            .unwrap()
+            .into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn i8_overflowing_add() {
         #[track_caller]
@@ -588,6 +741,7 @@ mod test {
         }
 
         const MAX: i8 = i8::MAX;
+        const MIN: i8 = i8::MIN;
 
         run(0, 0);
         run(0, 1);
@@ -608,6 +762,17 @@ mod test {
         run(MAX, 1);
         run(MAX, MAX - 1);
         run(MAX, MAX);
+
+        run(-1, -1);
+        run(-1, MIN);
+        run(-1, MAX);
+        run(MIN, -1);
+        run(MIN, 0);
+        run(MIN, 1);
+        run(MIN, MIN);
+        run(MIN, MAX);
+        run(MAX, -1);
+        run(MAX, MIN);
     }
 
     #[test]
@@ -640,6 +805,7 @@ mod test {
         }
 
         const MAX: i8 = i8::MAX;
+        const MIN: i8 = i8::MIN;
 
         run(0, 0);
         run(0, 1);
@@ -660,6 +826,17 @@ mod test {
         run(MAX, 1);
         run(MAX, MAX - 1);
         run(MAX, MAX);
+
+        run(-1, -1);
+        run(-1, MIN);
+        run(-1, MAX);
+        run(MIN, -1);
+        run(MIN, 0);
+        run(MIN, 1);
+        run(MIN, MIN);
+        run(MIN, MAX);
+        run(MAX, -1);
+        run(MAX, MIN);
     }
 
     #[test]
@@ -786,5 +963,29 @@ mod test {
             &[i8::MAX.into(), i8::MAX.into()],
             (i8::MAX as i16 * i8::MAX as i16).into(),
         );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[i8::MIN.into(), i8::MIN.into()],
+            (i8::MIN as i16 * i8::MIN as i16).into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[i8::MIN.into(), i8::MAX.into()],
+            (i8::MIN as i16 * i8::MAX as i16).into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[i8::MAX.into(), (-1i8).into()],
+            (-(i8::MAX as i16)).into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[(-3i8).into(), 7i8.into()],
+            (-21i16).into(),
+        );
     }
 }
@@ -50,3 +50,39 @@ pub fn build<'ctx, 'this>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::utils::test::{jit_enum, jit_struct, load_cairo, run_program_assert_output};
+    use starknet_types_core::felt::Felt;
+
+    #[test]
+    fn snapshot_take_array_unchanged() {
+        let program = load_cairo! {
+            fn run_test() -> (u32, u32, felt252, felt252) {
+                let mut numbers = array![1, 2, 3];
+                let snapshot = @numbers;
+
+                numbers.append(4);
+                numbers.append(5);
+
+                (snapshot.len(), numbers.len(), *snapshot.at(2), *numbers.at(4))
+            }
+        };
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[],
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(
+                    3u32.into(),
+                    5u32.into(),
+                    Felt::from(3).into(),
+                    Felt::from(5).into(),
+                ))
+            ),
+        );
+    }
+}
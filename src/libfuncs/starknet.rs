@@ -53,6 +53,8 @@ pub fn build<'ctx, 'this>(
     metadata: &mut MetadataStorage,
     selector: &StarkNetConcreteLibfunc,
 ) -> Result<()> {
+    check_supported(selector)?;
+
     match selector {
         StarkNetConcreteLibfunc::CallContract(info) => {
             build_call_contract(context, registry, entry, location, helper, metadata, info)
@@ -147,11 +149,24 @@ pub fn build<'ctx, 'this>(
         }
         #[cfg(not(feature = "with-cheatcode"))]
         StarkNetConcreteLibfunc::Testing(TestingConcreteLibfunc::Cheatcode(_)) => {
-            unimplemented!("feature 'with-cheatcode' is required to compile with cheatcode syscall")
+            unreachable!("rejected by check_supported")
         }
     }
 }
 
+/// Return the error [`build`] fails with when it can't generate the selected libfunc.
+pub fn check_supported(selector: &StarkNetConcreteLibfunc) -> Result<()> {
+    match selector {
+        #[cfg(not(feature = "with-cheatcode"))]
+        StarkNetConcreteLibfunc::Testing(TestingConcreteLibfunc::Cheatcode(_)) => {
+            Err(crate::error::Error::UnsupportedLibfunc(
+                "cheatcode (feature 'with-cheatcode' is required)".to_string(),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn build_call_contract<'ctx, 'this>(
     context: &'ctx Context,
     registry: &ProgramRegistry<CoreType, CoreLibfunc>,
@@ -3974,7 +3989,10 @@ pub fn build_send_message_to_l1<'ctx, 'this>(
 
 #[cfg(test)]
 mod test {
-    use crate::utils::test::{jit_enum, jit_struct, load_cairo, run_program_assert_output};
+    use crate::{
+        utils::test::{jit_enum, jit_struct, load_cairo, run_program_assert_output},
+        values::JitValue,
+    };
     use cairo_lang_sierra::program::Program;
     use lazy_static::lazy_static;
     use starknet_types_core::felt::Felt;
@@ -4015,6 +4033,41 @@ mod test {
                 storage_address_try_from_felt252(value)
             }
         };
+        static ref CONTRACT_ADDRESS_TRY_FROM_FELT252: (String, Program) = load_cairo! {
+            use starknet::contract_address::{ContractAddress, contract_address_try_from_felt252};
+
+            fn run_program(value: felt252) -> Option<ContractAddress> {
+                contract_address_try_from_felt252(value)
+            }
+        };
+        static ref CLASS_HASH_TRY_FROM_FELT252: (String, Program) = load_cairo! {
+            use starknet::class_hash::{ClassHash, class_hash_try_from_felt252};
+
+            fn run_program(value: felt252) -> Option<ClassHash> {
+                class_hash_try_from_felt252(value)
+            }
+        };
+        static ref CAIRO_KECCAK_EMPTY: (String, Program) = load_cairo! {
+            use core::keccak::cairo_keccak;
+
+            fn run_program() -> u256 {
+                let mut input = array![];
+                cairo_keccak(ref input, 0, 0)
+            }
+        };
+        static ref KECCAK_SYSCALL: (String, Program) = load_cairo! {
+            use starknet::{syscalls::keccak_syscall, SyscallResult};
+
+            fn run_program(full_block: bool) -> SyscallResult<u256> {
+                if full_block {
+                    keccak_syscall(
+                        array![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17].span(),
+                    )
+                } else {
+                    keccak_syscall(array![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13].span())
+                }
+            }
+        };
         static ref CLASS_HASH_CONST: (String, Program) = load_cairo! {
             use starknet::class_hash::{class_hash_const, ClassHash};
 
@@ -4238,4 +4291,94 @@ mod test {
             jit_enum!(1, jit_struct!()),
         );
     }
+
+    #[test]
+    fn address_try_from_felt252_bounds() {
+        let max = Felt::from_dec_str(
+            "3618502788666131106986593281521497120414687020801267626233049500247285301247",
+        )
+        .unwrap();
+        let limit = max + Felt::ONE;
+
+        for program in [
+            &*CONTRACT_ADDRESS_TRY_FROM_FELT252,
+            &*CLASS_HASH_TRY_FROM_FELT252,
+            &*STORAGE_ADDRESS_TRY_FROM_FELT252,
+        ] {
+            run_program_assert_output(
+                program,
+                "run_program",
+                &[max.into()],
+                jit_enum!(0, max.into()),
+            );
+            run_program_assert_output(
+                program,
+                "run_program",
+                &[limit.into()],
+                jit_enum!(1, jit_struct!()),
+            );
+        }
+
+        // The largest base plus the largest offset is the largest storage address.
+        run_program_assert_output(
+            &STORAGE_ADDRESS_FROM_BASE_AND_OFFSET,
+            "run_program",
+            &[
+                Felt::from_dec_str(
+                    "3618502788666131106986593281521497120414687020801267626233049500247285300992",
+                )
+                .unwrap()
+                .into(),
+                255u8.into(),
+            ],
+            max.into(),
+        );
+    }
+
+    #[test]
+    fn keccak_known_answers() {
+        // keccak256("") = 0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470, read
+        // as little-endian u64 words.
+        run_program_assert_output(
+            &CAIRO_KECCAK_EMPTY,
+            "run_program",
+            &[],
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(
+                    255231405766226377092371489783609217733u128.into(),
+                    149727777142906939876210071365441945829u128.into(),
+                ))
+            ),
+        );
+
+        // A single unpadded block is a single keccak-f[1600] permutation.
+        run_program_assert_output(
+            &KECCAK_SYSCALL,
+            "run_program",
+            &[jit_enum!(1, jit_struct!())],
+            jit_enum!(
+                0,
+                jit_struct!(
+                    314240319136055307035435783795864305109u128.into(),
+                    280360676274067731044963585568268448175u128.into(),
+                )
+            ),
+        );
+
+        // Inputs which aren't a multiple of the 17-word rate are rejected.
+        run_program_assert_output(
+            &KECCAK_SYSCALL,
+            "run_program",
+            &[jit_enum!(0, jit_struct!())],
+            jit_enum!(
+                1,
+                JitValue::Array(vec![Felt::from_dec_str(
+                    "460936957440218058043366978841036204030570905990848859175525"
+                )
+                .unwrap()
+                .into()])
+            ),
+        );
+    }
 }
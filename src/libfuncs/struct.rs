@@ -119,3 +119,215 @@ pub fn build_deconstruct<'ctx, 'this>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        context::NativeContext,
+        executor::JitNativeExecutor,
+        utils::{
+            find_function_id,
+            test::{jit_struct, load_cairo, run_program},
+        },
+        values::JitValue,
+        OptLevel,
+    };
+    use cairo_lang_sierra::ProgramParser;
+    use starknet_types_core::felt::Felt;
+
+    #[test]
+    fn run_deconstruct() {
+        let program = load_cairo!(
+            struct Pair {
+                first: felt252,
+                second: u8,
+            }
+
+            fn run_test(first: felt252, second: u8) -> u8 {
+                let pair = Pair { first, second };
+                let Pair { first: _, second } = pair;
+                second
+            }
+        );
+
+        let result = run_program(
+            &program,
+            "run_test",
+            &[JitValue::Felt252(10.into()), JitValue::Uint8(20)],
+        )
+        .return_value;
+        assert_eq!(result, JitValue::Uint8(20));
+    }
+
+    #[test]
+    fn run_deconstruct_multiple_results() {
+        // Every result of the deconstruction must be bound to its own variable. The values are
+        // used out of order and returned as multiple values to check they all reach the right uses.
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type felt252 = felt252;
+                    type Quad = Struct<ut@Quad, felt252, felt252, felt252, felt252>;
+
+                    libfunc struct_deconstruct<Quad> = struct_deconstruct<Quad>;
+                    libfunc felt252_sub = felt252_sub;
+                    libfunc store_temp<felt252> = store_temp<felt252>;
+
+                    struct_deconstruct<Quad>([0]) -> ([1], [2], [3], [4]);
+                    felt252_sub([1], [2]) -> ([5]);
+                    store_temp<felt252>([4]) -> ([4]);
+                    store_temp<felt252>([5]) -> ([5]);
+                    store_temp<felt252>([3]) -> ([3]);
+                    return([4], [5], [3]);
+
+                    run_test@0([0]: Quad) -> (felt252, felt252, felt252);
+                "#,
+            )
+            .unwrap();
+        let function_id = find_function_id(&program, "run_test");
+
+        let context = NativeContext::new();
+        let module = context.compile(&program, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+        let result = executor
+            .invoke_dynamic(
+                function_id,
+                &[jit_struct!(
+                    JitValue::Felt252(1.into()),
+                    JitValue::Felt252(20.into()),
+                    JitValue::Felt252(300.into()),
+                    JitValue::Felt252(4000.into()),
+                )],
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            result.return_value,
+            jit_struct!(
+                JitValue::Felt252(4000.into()),
+                JitValue::Felt252(Felt::from(-19)),
+                JitValue::Felt252(300.into()),
+            )
+        );
+    }
+
+    #[test]
+    fn run_deconstruct_nested() {
+        let program = load_cairo!(
+            struct Inner {
+                a: felt252,
+                b: felt252,
+            }
+
+            struct Outer {
+                inner: Inner,
+                c: u32,
+            }
+
+            fn run_test(a: felt252, b: felt252, c: u32) -> (Inner, u32) {
+                let outer = Outer {
+                    inner: Inner { a, b },
+                    c,
+                };
+                let Outer { inner, c } = outer;
+                (inner, c)
+            }
+        );
+
+        let result = run_program(
+            &program,
+            "run_test",
+            &[
+                JitValue::Felt252(1.into()),
+                JitValue::Felt252(2.into()),
+                JitValue::Uint32(3),
+            ],
+        )
+        .return_value;
+        assert_eq!(
+            result,
+            jit_struct!(
+                jit_struct!(JitValue::Felt252(1.into()), JitValue::Felt252(2.into())),
+                JitValue::Uint32(3),
+            )
+        );
+    }
+
+    #[test]
+    fn run_construct_named() {
+        let program = load_cairo!(
+            #[derive(Drop)]
+            struct Point {
+                x: felt252,
+                y: felt252,
+            }
+
+            fn run_test(x: felt252, y: felt252) -> Point {
+                Point { x, y }
+            }
+        );
+
+        let result = run_program(
+            &program,
+            "run_test",
+            &[JitValue::Felt252(1.into()), JitValue::Felt252(2.into())],
+        )
+        .return_value;
+        assert_eq!(
+            result,
+            jit_struct!(JitValue::Felt252(1.into()), JitValue::Felt252(2.into()))
+        );
+        assert!(matches!(
+            result,
+            JitValue::Struct { debug_name: Some(ref name), .. } if name.ends_with("Point")
+        ));
+    }
+
+    #[test]
+    fn run_round_trip_deeply_nested() {
+        let program = load_cairo!(
+            #[derive(Drop)]
+            struct Innermost {
+                value: felt252,
+            }
+
+            #[derive(Drop)]
+            struct Inner {
+                innermost: Innermost,
+            }
+
+            #[derive(Drop)]
+            struct Outer {
+                inner: Inner,
+                tag: u8,
+            }
+
+            fn run_test(outer: Outer) -> Outer {
+                let value = outer.inner.innermost.value + 1;
+                let innermost = Innermost { value };
+                Outer {
+                    inner: Inner { innermost },
+                    tag: outer.tag,
+                }
+            }
+        );
+
+        let result = run_program(
+            &program,
+            "run_test",
+            &[jit_struct!(
+                jit_struct!(jit_struct!(JitValue::Felt252(41.into()))),
+                JitValue::Uint8(7),
+            )],
+        )
+        .return_value;
+        assert_eq!(
+            result,
+            jit_struct!(
+                jit_struct!(jit_struct!(JitValue::Felt252(42.into()))),
+                JitValue::Uint8(7),
+            )
+        );
+    }
+}
@@ -579,6 +579,13 @@ mod test {
                 u128_byte_reverse(value)
             }
         };
+        static ref U128_BYTE_REVERSE_TWICE: (String, Program) = load_cairo! {
+            extern fn u128_byte_reverse(input: u128) -> u128 implicits(Bitwise) nopanic;
+
+            fn run_test(value: u128) -> u128 {
+                u128_byte_reverse(u128_byte_reverse(value))
+            }
+        };
         static ref U128_CONST: (String, Program) = load_cairo! {
             fn run_test() -> u128 {
                 1234567890
@@ -621,6 +628,22 @@ mod test {
                 }
             }
         };
+        static ref U128_IS_ZERO_DIV: (String, Program) = load_cairo! {
+            use zeroable::IsZeroResult;
+
+            extern fn u128_is_zero(a: u128) -> IsZeroResult<u128> implicits() nopanic;
+            extern fn u128_safe_divmod(lhs: u128, rhs: NonZero<u128>) -> (u128, u128) implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u128, rhs: u128) -> Option<u128> nopanic {
+                match u128_is_zero(rhs) {
+                    IsZeroResult::Zero(_) => Option::None,
+                    IsZeroResult::NonZero(rhs) => {
+                        let (q, _) = u128_safe_divmod(lhs, rhs);
+                        Option::Some(q)
+                    },
+                }
+            }
+        };
         static ref U128_ADD: (String, Program) = load_cairo! {
             fn run_test(lhs: u128, rhs: u128) -> u128 {
                 lhs + rhs
@@ -631,6 +654,11 @@ mod test {
                 lhs - rhs
             }
         };
+        static ref U128_MUL: (String, Program) = load_cairo! {
+            fn run_test(lhs: u128, rhs: u128) -> u128 {
+                lhs * rhs
+            }
+        };
         static ref U128_WIDEMUL: (String, Program) = load_cairo! {
             use integer::u128_wide_mul;
             fn run_test(lhs: u128, rhs: u128) -> (u128, u128) {
@@ -673,6 +701,30 @@ mod test {
             &[0x12345678_90ABCDEF_12345678_90ABCDEFu128.into()],
             0xEFCDAB90_78563412_EFCDAB90_78563412u128.into(),
         );
+        run_program_assert_output(
+            &U128_BYTE_REVERSE,
+            "run_test",
+            &[0x01020304_05060708_090A0B0C_0D0E0F10u128.into()],
+            0x100F0E0D_0C0B0A09_08070605_04030201u128.into(),
+        );
+    }
+
+    #[test]
+    fn u128_byte_reverse_involution() {
+        for value in [
+            0u128,
+            1,
+            0x01020304_05060708_090A0B0C_0D0E0F10,
+            0xFF000000_00000000_00000000_000000AA,
+            u128::MAX,
+        ] {
+            run_program_assert_output(
+                &U128_BYTE_REVERSE_TWICE,
+                "run_test",
+                &[value.into()],
+                value.into(),
+            );
+        }
     }
 
     #[test]
@@ -742,6 +794,25 @@ mod test {
             &[max_value.into(), max_value.into()],
             jit_enum!(0, jit_struct!(jit_struct!(1u128.into(), 0u128.into()))),
         );
+
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u128.into(), 7u128.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u128.into(), 0u128.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u128.into(), 3u128.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(2u128.into(), 1u128.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[3u128.into(), 7u128.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u128.into(), 3u128.into()))),
+        );
     }
 
     #[test]
@@ -807,6 +878,55 @@ mod test {
             ],
             jit_enum!(1, jit_struct!(1u128.into(), 0u128.into())),
         );
+
+        run_program_assert_output(
+            &U128_FROM_FELT252,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(
+                1,
+                jit_struct!(
+                    0x0800_0000_0000_0011_0000_0000_0000_0000u128.into(),
+                    0u128.into()
+                )
+            ),
+        );
+    }
+
+    #[test]
+    fn u128_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<u128> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into()],
+            jit_enum!(0, 0u128.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(u128::MAX).into()],
+            jit_enum!(0, u128::MAX.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(u128::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(1, jit_struct!()),
+        );
     }
 
     #[test]
@@ -825,6 +945,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn u128_is_zero_div() {
+        run_program_assert_output(
+            &U128_IS_ZERO_DIV,
+            "run_test",
+            &[10u128.into(), 0u128.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &U128_IS_ZERO_DIV,
+            "run_test",
+            &[10u128.into(), 3u128.into()],
+            jit_enum!(0, 3u128.into()),
+        );
+        run_program_assert_output(
+            &U128_IS_ZERO_DIV,
+            "run_test",
+            &[u128::MAX.into(), u128::MAX.into()],
+            jit_enum!(0, 1u128.into()),
+        );
+    }
+
     #[test]
     fn u128_add() {
         #[track_caller]
@@ -929,6 +1071,47 @@ mod test {
         run(MAX, MAX);
     }
 
+    #[test]
+    fn u128_mul() {
+        #[track_caller]
+        fn run(lhs: u128, rhs: u128) {
+            let program = &U128_MUL;
+            let error = Felt::from_bytes_be_slice(b"u128_mul Overflow");
+
+            match lhs.checked_mul(rhs) {
+                Some(result) => {
+                    run_program_assert_output(
+                        program,
+                        "run_test",
+                        &[lhs.into(), rhs.into()],
+                        jit_enum!(0, jit_struct!(result.into())),
+                    );
+                }
+                None => {
+                    run_program_assert_output(
+                        program,
+                        "run_test",
+                        &[lhs.into(), rhs.into()],
+                        jit_panic!(JitValue::Felt252(error)),
+                    );
+                }
+            }
+        }
+
+        const MAX: u128 = u128::MAX;
+
+        run(0, MAX);
+        run(1, MAX);
+        run(MAX, 1);
+        run(u64::MAX as u128, u64::MAX as u128);
+        run(u64::MAX as u128 + 1, u64::MAX as u128);
+        run(u64::MAX as u128 + 1, u64::MAX as u128 + 1);
+        run(0x1234_5678_9ABC_DEF0, 0x0FED_CBA9_8765_4321);
+        run(MAX / 3, 3);
+        run(MAX / 3, 4);
+        run(MAX, MAX);
+    }
+
     #[test]
     fn u128_to_felt252() {
         let program = &U128_TO_FELT252;
@@ -943,6 +1126,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn u128_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: u128) -> (felt252, Option<u128>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[0u128.into()],
+            jit_struct!(Felt::ZERO.into(), jit_enum!(0, 0u128.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[u128::MAX.into()],
+            jit_struct!(Felt::from(u128::MAX).into(), jit_enum!(0, u128::MAX.into())),
+        );
+    }
+
     #[test]
     fn u128_sqrt() {
         let program = &U128_SQRT;
@@ -995,5 +1203,75 @@ mod test {
             &[u128::MAX.into(), u128::MAX.into()],
             jit_struct!((u128::MAX - 1).into(), 1u128.into()),
         );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[u128::MAX.into(), 2u128.into()],
+            jit_struct!(1u128.into(), (u128::MAX - 1).into()),
+        );
+    }
+
+    #[test]
+    fn u128_overflowing_add_wrapping() {
+        let program = load_cairo!(
+            extern fn u128_overflowing_add(lhs: u128, rhs: u128) -> Result<u128, u128> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u128, rhs: u128) -> Result<u128, u128> {
+                u128_overflowing_add(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u128, rhs: u128) {
+            let (result, overflow) = lhs.overflowing_add(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u128 = u128::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX - 1);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, 1);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX);
+    }
+
+    #[test]
+    fn u128_overflowing_sub_wrapping() {
+        let program = load_cairo!(
+            extern fn u128_overflowing_sub(lhs: u128, rhs: u128) -> Result<u128, u128> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u128, rhs: u128) -> Result<u128, u128> {
+                u128_overflowing_sub(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u128, rhs: u128) {
+            let (result, overflow) = lhs.overflowing_sub(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u128 = u128::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, 1);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX - 1);
+        run(&program, MAX, MAX);
     }
 }
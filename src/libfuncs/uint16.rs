@@ -646,6 +646,31 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], Felt::from(2).into());
     }
 
+    #[test]
+    fn u16_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: u16) -> (felt252, Option<u16>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[0u16.into()],
+            jit_struct!(Felt::ZERO.into(), jit_enum!(0, 0u16.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[u16::MAX.into()],
+            jit_struct!(Felt::from(u16::MAX).into(), jit_enum!(0, u16::MAX.into())),
+        );
+    }
+
     #[test]
     fn u16_from_felt252() {
         let program = load_cairo!(
@@ -664,6 +689,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn u16_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<u16> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into()],
+            jit_enum!(0, 0u16.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(u16::MAX).into()],
+            jit_enum!(0, u16::MAX.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(u16::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn u16_overflowing_add() {
         #[track_caller]
@@ -818,63 +879,84 @@ mod test {
 
     #[test]
     fn u16_safe_divmod() {
-        let program = &U16_IS_ZERO;
+        let program = &U16_SAFE_DIVMOD;
+        let max_value = 0xFFFFu16;
+        let error = JitValue::Felt252(Felt::from_bytes_be_slice(b"Division by 0"));
 
         run_program_assert_output(
             program,
             "run_test",
             &[0u16.into(), 0u16.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[0u16.into(), 1u16.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(0u16.into(), 0u16.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0u16.into(), 0xFFFFu16.into()],
-            jit_enum!(1, jit_struct!()),
+            &[0u16.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u16.into(), 0u16.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
             &[1u16.into(), 0u16.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[1u16.into(), 1u16.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(1u16.into(), 0u16.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[1u16.into(), 0xFFFFu16.into()],
-            jit_enum!(0, jit_struct!()),
+            &[1u16.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u16.into(), 1u16.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFu16.into(), 0u16.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 0u16.into()],
+            jit_panic!(error),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFu16.into(), 1u16.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 1u16.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(max_value.into(), 0u16.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFu16.into(), 0xFFFFu16.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u16.into(), 0u16.into()))),
+        );
+
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u16.into(), 7u16.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u16.into(), 0u16.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u16.into(), 3u16.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(2u16.into(), 1u16.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[3u16.into(), 7u16.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u16.into(), 3u16.into()))),
         );
     }
 
@@ -928,7 +1010,77 @@ mod test {
             program,
             "run_test",
             &[u16::MAX.into(), u16::MAX.into()],
-            (u16::MAX as u32 * u16::MAX as u32).into(),
+            0xFFFE_0001u32.into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[u16::MAX.into(), 2u16.into()],
+            0x0001_FFFEu32.into(),
+        );
+    }
+
+    #[test]
+    fn u16_overflowing_add_wrapping() {
+        let program = load_cairo!(
+            extern fn u16_overflowing_add(lhs: u16, rhs: u16) -> Result<u16, u16> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u16, rhs: u16) -> Result<u16, u16> {
+                u16_overflowing_add(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u16, rhs: u16) {
+            let (result, overflow) = lhs.overflowing_add(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u16 = u16::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX - 1);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, 1);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX);
+    }
+
+    #[test]
+    fn u16_overflowing_sub_wrapping() {
+        let program = load_cairo!(
+            extern fn u16_overflowing_sub(lhs: u16, rhs: u16) -> Result<u16, u16> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u16, rhs: u16) -> Result<u16, u16> {
+                u16_overflowing_sub(lhs, rhs)
+            }
         );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u16, rhs: u16) {
+            let (result, overflow) = lhs.overflowing_sub(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u16 = u16::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, 1);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX - 1);
+        run(&program, MAX, MAX);
     }
 }
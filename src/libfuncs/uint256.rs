@@ -1161,6 +1161,42 @@ mod test {
                 ))
             ),
         );
+
+        let lhs = (
+            0x12345678_9ABCDEF0_0FEDCBA9_87654321u128,
+            0xDEADBEEF_00000000_CAFEBABE_00000001u128,
+        );
+        run(
+            lhs,
+            (0, 7),
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(
+                    jit_struct!(
+                        0xB218D222_24924924_AF48F61B_24924924u128.into(),
+                        0x0299C335_CCF668FD_B921F885_EEC552BBu128.into(),
+                    ),
+                    jit_struct!(5u128.into(), 0u128.into()),
+                ))
+            ),
+        );
+        run(
+            lhs,
+            (1, 5),
+            jit_enum!(
+                0,
+                jit_struct!(jit_struct!(
+                    jit_struct!(
+                        0x12345678_9ABCDEF0_0FEDCBA9_87654321u128.into(),
+                        0u128.into(),
+                    ),
+                    jit_struct!(
+                        0x83A80E93_FA4FA550_7B59C06E_5B05B05Cu128.into(),
+                        0u128.into(),
+                    ),
+                ))
+            ),
+        );
     }
 
     #[test]
@@ -1276,5 +1312,39 @@ mod test {
             (5, 0),
             jit_enum!(0, jit_struct!(3u128.into(), 0u128.into())),
         );
+
+        // Inverse of 3 modulo the prime 2^255 - 19.
+        run(
+            (3, 0),
+            (
+                340282366920938463463374607431768211437,
+                170141183460469231731687303715884105727,
+            ),
+            jit_enum!(
+                0,
+                jit_struct!(
+                    113427455640312821154458202477256070473u128.into(),
+                    113427455640312821154458202477256070485u128.into(),
+                )
+            ),
+        );
+        // `n - 1` is its own inverse modulo `n` (here, the secp256k1 curve order).
+        run(
+            (
+                248144347276217270074328348468568277312,
+                340282366920938463463374607431768211454,
+            ),
+            (
+                248144347276217270074328348468568277313,
+                340282366920938463463374607431768211454,
+            ),
+            jit_enum!(
+                0,
+                jit_struct!(
+                    248144347276217270074328348468568277312u128.into(),
+                    340282366920938463463374607431768211454u128.into(),
+                )
+            ),
+        );
     }
 }
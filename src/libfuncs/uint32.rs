@@ -614,6 +614,31 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], Felt::from(2).into());
     }
 
+    #[test]
+    fn u32_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: u32) -> (felt252, Option<u32>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[0u32.into()],
+            jit_struct!(Felt::ZERO.into(), jit_enum!(0, 0u32.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[u32::MAX.into()],
+            jit_struct!(Felt::from(u32::MAX).into(), jit_enum!(0, u32::MAX.into())),
+        );
+    }
+
     #[test]
     fn u32_from_felt252() {
         let program = load_cairo!(
@@ -635,6 +660,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn u32_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<u32> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into()],
+            jit_enum!(0, 0u32.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(u32::MAX).into()],
+            jit_enum!(0, u32::MAX.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(u32::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn u32_overflowing_add() {
         #[track_caller]
@@ -789,63 +850,84 @@ mod test {
 
     #[test]
     fn u32_safe_divmod() {
-        let program = &U32_IS_ZERO;
+        let program = &U32_SAFE_DIVMOD;
+        let max_value = 0xFFFFFFFFu32;
+        let error = JitValue::Felt252(Felt::from_bytes_be_slice(b"Division by 0"));
 
         run_program_assert_output(
             program,
             "run_test",
             &[0u32.into(), 0u32.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[0u32.into(), 1u32.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(0u32.into(), 0u32.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0u32.into(), 0xFFFFFFFFu32.into()],
-            jit_enum!(1, jit_struct!()),
+            &[0u32.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u32.into(), 0u32.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
             &[1u32.into(), 0u32.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[1u32.into(), 1u32.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(1u32.into(), 0u32.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[1u32.into(), 0xFFFFFFFFu32.into()],
-            jit_enum!(0, jit_struct!()),
+            &[1u32.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u32.into(), 1u32.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFFFFFu32.into(), 0u32.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 0u32.into()],
+            jit_panic!(error),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFFFFFu32.into(), 1u32.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 1u32.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(max_value.into(), 0u32.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFFFFFu32.into(), 0xFFFFFFFFu32.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u32.into(), 0u32.into()))),
+        );
+
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u32.into(), 7u32.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u32.into(), 0u32.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u32.into(), 3u32.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(2u32.into(), 1u32.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[3u32.into(), 7u32.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u32.into(), 3u32.into()))),
         );
     }
 
@@ -899,7 +981,77 @@ mod test {
             program,
             "run_test",
             &[u32::MAX.into(), u32::MAX.into()],
-            (u32::MAX as u64 * u32::MAX as u64).into(),
+            0xFFFF_FFFE_0000_0001u64.into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[u32::MAX.into(), 2u32.into()],
+            0x0000_0001_FFFF_FFFEu64.into(),
+        );
+    }
+
+    #[test]
+    fn u32_overflowing_add_wrapping() {
+        let program = load_cairo!(
+            extern fn u32_overflowing_add(lhs: u32, rhs: u32) -> Result<u32, u32> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u32, rhs: u32) -> Result<u32, u32> {
+                u32_overflowing_add(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u32, rhs: u32) {
+            let (result, overflow) = lhs.overflowing_add(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u32 = u32::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX - 1);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, 1);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX);
+    }
+
+    #[test]
+    fn u32_overflowing_sub_wrapping() {
+        let program = load_cairo!(
+            extern fn u32_overflowing_sub(lhs: u32, rhs: u32) -> Result<u32, u32> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u32, rhs: u32) -> Result<u32, u32> {
+                u32_overflowing_sub(lhs, rhs)
+            }
         );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u32, rhs: u32) {
+            let (result, overflow) = lhs.overflowing_sub(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u32 = u32::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, 1);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX - 1);
+        run(&program, MAX, MAX);
     }
 }
@@ -307,5 +307,15 @@ mod test {
             (BigUint::one() << 256) + 1u32,
             0u32.into(),
         );
+
+        // All four limbs non-zero, divided by a 129-bit value.
+        let lhs = BigUint::parse_bytes(
+            b"0123456789ABCDEF0FEDCBA987654321DEADBEEFCAFEBABE0000000100000002\
+              FFFFFFFF00000000123412341234123487654321876543210F0F0F0F0F0F0F0F",
+            16,
+        )
+        .unwrap();
+        let rhs = (BigUint::one() << 128u32) + 0x0BADC0DE_DEADBEEF_00000000_00000007u128;
+        r2(lhs.clone(), rhs.clone(), &lhs / &rhs, &lhs % &rhs);
     }
 }
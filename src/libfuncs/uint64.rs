@@ -614,6 +614,31 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], Felt::from(2).into());
     }
 
+    #[test]
+    fn u64_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: u64) -> (felt252, Option<u64>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[0u64.into()],
+            jit_struct!(Felt::ZERO.into(), jit_enum!(0, 0u64.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[u64::MAX.into()],
+            jit_struct!(Felt::from(u64::MAX).into(), jit_enum!(0, u64::MAX.into())),
+        );
+    }
+
     #[test]
     fn u64_from_felt252() {
         let program = load_cairo!(
@@ -638,6 +663,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn u64_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<u64> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into()],
+            jit_enum!(0, 0u64.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(u64::MAX).into()],
+            jit_enum!(0, u64::MAX.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(u64::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn u64_overflowing_add() {
         #[track_caller]
@@ -792,63 +853,84 @@ mod test {
 
     #[test]
     fn u64_safe_divmod() {
-        let program = &U64_IS_ZERO;
+        let program = &U64_SAFE_DIVMOD;
+        let max_value = 0xFFFFFFFFFFFFFFFFu64;
+        let error = JitValue::Felt252(Felt::from_bytes_be_slice(b"Division by 0"));
 
         run_program_assert_output(
             program,
             "run_test",
             &[0u64.into(), 0u64.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[0u64.into(), 1u64.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(0u64.into(), 0u64.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0u64.into(), 0xFFFFFFFFFFFFFFFFu64.into()],
-            jit_enum!(1, jit_struct!()),
+            &[0u64.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u64.into(), 0u64.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
             &[1u64.into(), 0u64.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[1u64.into(), 1u64.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(1u64.into(), 0u64.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[1u64.into(), 0xFFFFFFFFFFFFFFFFu64.into()],
-            jit_enum!(0, jit_struct!()),
+            &[1u64.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u64.into(), 1u64.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFFFFFFFFFFFFFu64.into(), 0u64.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 0u64.into()],
+            jit_panic!(error),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFFFFFFFFFFFFFu64.into(), 1u64.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 1u64.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(max_value.into(), 0u64.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFFFFFFFFFFFFFFFu64.into(), 0xFFFFFFFFFFFFFFFFu64.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u64.into(), 0u64.into()))),
+        );
+
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u64.into(), 7u64.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u64.into(), 0u64.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u64.into(), 3u64.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(2u64.into(), 1u64.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[3u64.into(), 7u64.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u64.into(), 3u64.into()))),
         );
     }
 
@@ -907,7 +989,77 @@ mod test {
             program,
             "run_test",
             &[u64::MAX.into(), u64::MAX.into()],
-            (u64::MAX as u128 * u64::MAX as u128).into(),
+            0xFFFF_FFFF_FFFF_FFFE_0000_0000_0000_0001u128.into(),
         );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[u64::MAX.into(), 2u64.into()],
+            0x0000_0000_0000_0001_FFFF_FFFF_FFFF_FFFEu128.into(),
+        );
+    }
+
+    #[test]
+    fn u64_overflowing_add_wrapping() {
+        let program = load_cairo!(
+            extern fn u64_overflowing_add(lhs: u64, rhs: u64) -> Result<u64, u64> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u64, rhs: u64) -> Result<u64, u64> {
+                u64_overflowing_add(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u64, rhs: u64) {
+            let (result, overflow) = lhs.overflowing_add(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u64 = u64::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX - 1);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, 1);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX);
+    }
+
+    #[test]
+    fn u64_overflowing_sub_wrapping() {
+        let program = load_cairo!(
+            extern fn u64_overflowing_sub(lhs: u64, rhs: u64) -> Result<u64, u64> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u64, rhs: u64) -> Result<u64, u64> {
+                u64_overflowing_sub(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u64, rhs: u64) {
+            let (result, overflow) = lhs.overflowing_sub(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u64 = u64::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, 1);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX - 1);
+        run(&program, MAX, MAX);
     }
 }
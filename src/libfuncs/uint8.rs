@@ -609,6 +609,31 @@ mod test {
         run_program_assert_output(&program, "run_test", &[], Felt::from(2).into());
     }
 
+    #[test]
+    fn u8_to_felt252_round_trip() {
+        let program = load_cairo!(
+            use traits::{Into, TryInto};
+
+            fn run_test(value: u8) -> (felt252, Option<u8>) {
+                let value: felt252 = value.into();
+                (value, value.try_into())
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[0u8.into()],
+            jit_struct!(Felt::ZERO.into(), jit_enum!(0, 0u8.into())),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[u8::MAX.into()],
+            jit_struct!(Felt::from(u8::MAX).into(), jit_enum!(0, u8::MAX.into())),
+        );
+    }
+
     #[test]
     fn u8_from_felt252() {
         let program = load_cairo!(
@@ -627,6 +652,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn u8_try_from_felt252_bounds() {
+        let program = load_cairo!(
+            use traits::TryInto;
+
+            fn run_test(value: felt252) -> Option<u8> {
+                value.try_into()
+            }
+        );
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into()],
+            jit_enum!(0, 0u8.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(u8::MAX).into()],
+            jit_enum!(0, u8::MAX.into()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[(Felt::from(u8::MAX) + Felt::ONE).into()],
+            jit_enum!(1, jit_struct!()),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::MAX.into()],
+            jit_enum!(1, jit_struct!()),
+        );
+    }
+
     #[test]
     fn u8_overflowing_add() {
         #[track_caller]
@@ -781,63 +842,84 @@ mod test {
 
     #[test]
     fn u8_safe_divmod() {
-        let program = &U8_IS_ZERO;
+        let program = &U8_SAFE_DIVMOD;
+        let max_value = 0xFFu8;
+        let error = JitValue::Felt252(Felt::from_bytes_be_slice(b"Division by 0"));
 
         run_program_assert_output(
             program,
             "run_test",
             &[0u8.into(), 0u8.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[0u8.into(), 1u8.into()],
-            jit_enum!(1, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(0u8.into(), 0u8.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0u8.into(), 0xFFu8.into()],
-            jit_enum!(1, jit_struct!()),
+            &[0u8.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u8.into(), 0u8.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
             &[1u8.into(), 0u8.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_panic!(error.clone()),
         );
         run_program_assert_output(
             program,
             "run_test",
             &[1u8.into(), 1u8.into()],
-            jit_enum!(0, jit_struct!()),
+            jit_enum!(0, jit_struct!(jit_struct!(1u8.into(), 0u8.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[1u8.into(), 0xFFu8.into()],
-            jit_enum!(0, jit_struct!()),
+            &[1u8.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u8.into(), 1u8.into()))),
         );
 
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFu8.into(), 0u8.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 0u8.into()],
+            jit_panic!(error),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFu8.into(), 1u8.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), 1u8.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(max_value.into(), 0u8.into()))),
         );
         run_program_assert_output(
             program,
             "run_test",
-            &[0xFFu8.into(), 0xFFu8.into()],
-            jit_enum!(0, jit_struct!()),
+            &[max_value.into(), max_value.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u8.into(), 0u8.into()))),
+        );
+
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u8.into(), 7u8.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(1u8.into(), 0u8.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[7u8.into(), 3u8.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(2u8.into(), 1u8.into()))),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[3u8.into(), 7u8.into()],
+            jit_enum!(0, jit_struct!(jit_struct!(0u8.into(), 3u8.into()))),
         );
     }
 
@@ -871,7 +953,77 @@ mod test {
             program,
             "run_test",
             &[u8::MAX.into(), u8::MAX.into()],
-            (u8::MAX as u16 * u8::MAX as u16).into(),
+            0xFE01u16.into(),
+        );
+        run_program_assert_output(
+            program,
+            "run_test",
+            &[u8::MAX.into(), 2u8.into()],
+            0x01FEu16.into(),
+        );
+    }
+
+    #[test]
+    fn u8_overflowing_add_wrapping() {
+        let program = load_cairo!(
+            extern fn u8_overflowing_add(lhs: u8, rhs: u8) -> Result<u8, u8> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u8, rhs: u8) -> Result<u8, u8> {
+                u8_overflowing_add(lhs, rhs)
+            }
+        );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u8, rhs: u8) {
+            let (result, overflow) = lhs.overflowing_add(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u8 = u8::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX - 1);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, 1);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX);
+    }
+
+    #[test]
+    fn u8_overflowing_sub_wrapping() {
+        let program = load_cairo!(
+            extern fn u8_overflowing_sub(lhs: u8, rhs: u8) -> Result<u8, u8> implicits(RangeCheck) nopanic;
+
+            fn run_test(lhs: u8, rhs: u8) -> Result<u8, u8> {
+                u8_overflowing_sub(lhs, rhs)
+            }
         );
+
+        #[track_caller]
+        fn run(program: &(String, Program), lhs: u8, rhs: u8) {
+            let (result, overflow) = lhs.overflowing_sub(rhs);
+            run_program_assert_output(
+                program,
+                "run_test",
+                &[lhs.into(), rhs.into()],
+                jit_enum!(overflow as usize, result.into()),
+            );
+        }
+
+        const MAX: u8 = u8::MAX;
+
+        run(&program, 0, 0);
+        run(&program, 0, 1);
+        run(&program, 0, MAX);
+        run(&program, 1, MAX);
+        run(&program, MAX - 1, MAX);
+        run(&program, MAX, MAX - 1);
+        run(&program, MAX, MAX);
     }
 }
@@ -28,3 +28,46 @@ pub fn build<'ctx>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::utils::test::{load_cairo, run_program_assert_output};
+    use starknet_types_core::felt::Felt;
+
+    #[test]
+    fn run_if_else() {
+        let program = load_cairo! {
+            fn run_test(x: felt252) -> felt252 {
+                let y = if x == 0 {
+                    1
+                } else {
+                    2
+                };
+                y * 10
+            }
+        };
+
+        // Both libfuncs must be present for the test to be meaningful.
+        let generic_ids = program
+            .1
+            .libfunc_declarations
+            .iter()
+            .map(|decl| decl.long_id.generic_id.0.as_str())
+            .collect::<Vec<_>>();
+        assert!(generic_ids.contains(&"branch_align"));
+        assert!(generic_ids.contains(&"jump"));
+
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::ZERO.into()],
+            Felt::from(10).into(),
+        );
+        run_program_assert_output(
+            &program,
+            "run_test",
+            &[Felt::from(5).into()],
+            Felt::from(20).into(),
+        );
+    }
+}
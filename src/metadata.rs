@@ -22,6 +22,8 @@ pub mod realloc_bindings;
 pub mod runtime_bindings;
 pub mod snapshot_clones;
 pub mod tail_recursion;
+pub mod trace_statements;
+pub mod trap_analysis;
 
 /// Metadata container.
 #[cfg_attr(not(feature = "with-debug-utils"), derive(Default))]
@@ -65,6 +65,21 @@ impl GasMetadata {
         }
     }
 
+    /// Computes the metadata for a program the way the compiler does by default: gas costs are only
+    /// solved when the program uses the gas builtin.
+    pub fn for_program(sierra_program: &Program) -> Result<GasMetadata, GasMetadataError> {
+        let has_gas_builtin = sierra_program
+            .type_declarations
+            .iter()
+            .any(|decl| decl.long_id.generic_id.0.as_str() == "GasBuiltin");
+
+        if has_gas_builtin {
+            Self::new(sierra_program, Some(MetadataComputationConfig::default()))
+        } else {
+            Self::new(sierra_program, None)
+        }
+    }
+
     /// Returns the initial value for the gas counter.
     /// If `available_gas` is None returns 0.
     pub fn get_initial_available_gas(
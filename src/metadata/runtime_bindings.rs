@@ -30,6 +30,9 @@ enum RuntimeBinding {
     DictGasRefund,
     DictInsert,
     DictFree,
+    Trap,
+    PeekTrap,
+    Trace,
     #[cfg(feature = "with-cheatcode")]
     VtableCheatcode,
 }
@@ -423,6 +426,140 @@ impl RuntimeBindingsMeta {
         )))
     }
 
+    /// Register if necessary, then invoke the `trap()` function.
+    ///
+    /// The trap is recorded by the runtime and reported by the executor once the invocation
+    /// returns, so the generated code must return (with poisoned values) afterwards.
+    pub fn trap<'c, 'a>(
+        &mut self,
+        context: &'c Context,
+        module: &Module,
+        block: &'a Block<'c>,
+        code: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<OperationRef<'c, 'a>>
+    where
+        'c: 'a,
+    {
+        if self.active_map.insert(RuntimeBinding::Trap) {
+            module.body().append_operation(func::func(
+                context,
+                StringAttribute::new(context, "cairo_native__trap"),
+                TypeAttribute::new(
+                    FunctionType::new(context, &[IntegerType::new(context, 64).into()], &[]).into(),
+                ),
+                Region::new(),
+                &[(
+                    Identifier::new(context, "sym_visibility"),
+                    StringAttribute::new(context, "private").into(),
+                )],
+                Location::unknown(context),
+            ));
+        }
+
+        Ok(block.append_operation(func::call(
+            context,
+            FlatSymbolRefAttribute::new(context, "cairo_native__trap"),
+            &[code],
+            &[],
+            location,
+        )))
+    }
+
+    /// Register if necessary, then invoke the `peek_trap()` function.
+    ///
+    /// Returns the code of the pending trap as an `i64`, which is zero if there isn't any.
+    pub fn peek_trap<'c, 'a>(
+        &mut self,
+        context: &'c Context,
+        module: &Module,
+        block: &'a Block<'c>,
+        location: Location<'c>,
+    ) -> Result<OperationRef<'c, 'a>>
+    where
+        'c: 'a,
+    {
+        if self.active_map.insert(RuntimeBinding::PeekTrap) {
+            module.body().append_operation(func::func(
+                context,
+                StringAttribute::new(context, "cairo_native__peek_trap"),
+                TypeAttribute::new(
+                    FunctionType::new(context, &[], &[IntegerType::new(context, 64).into()]).into(),
+                ),
+                Region::new(),
+                &[(
+                    Identifier::new(context, "sym_visibility"),
+                    StringAttribute::new(context, "private").into(),
+                )],
+                Location::unknown(context),
+            ));
+        }
+
+        Ok(block.append_operation(func::call(
+            context,
+            FlatSymbolRefAttribute::new(context, "cairo_native__peek_trap"),
+            &[],
+            &[IntegerType::new(context, 64).into()],
+            location,
+        )))
+    }
+
+    /// Register if necessary, then invoke the `trace()` function.
+    ///
+    /// Reports the value of a statement's result variable, stored at `value_ptr`, to the trace
+    /// hook installed in the runtime.
+    #[allow(clippy::too_many_arguments)]
+    pub fn trace<'c, 'a>(
+        &mut self,
+        context: &'c Context,
+        module: &Module,
+        block: &'a Block<'c>,
+        statement_idx: Value<'c, '_>,
+        var_id: Value<'c, '_>,
+        type_id: Value<'c, '_>,
+        value_ptr: Value<'c, '_>,
+        value_size: Value<'c, '_>,
+        location: Location<'c>,
+    ) -> Result<OperationRef<'c, 'a>>
+    where
+        'c: 'a,
+    {
+        if self.active_map.insert(RuntimeBinding::Trace) {
+            module.body().append_operation(func::func(
+                context,
+                StringAttribute::new(context, "cairo_native__trace"),
+                TypeAttribute::new(
+                    FunctionType::new(
+                        context,
+                        &[
+                            IntegerType::new(context, 64).into(),
+                            IntegerType::new(context, 64).into(),
+                            IntegerType::new(context, 64).into(),
+                            llvm::r#type::pointer(context, 0),
+                            IntegerType::new(context, 64).into(),
+                        ],
+                        &[],
+                    )
+                    .into(),
+                ),
+                Region::new(),
+                &[(
+                    Identifier::new(context, "sym_visibility"),
+                    StringAttribute::new(context, "private").into(),
+                )],
+                Location::unknown(context),
+            ));
+        }
+
+        Ok(block.append_operation(func::call(
+            context,
+            FlatSymbolRefAttribute::new(context, "cairo_native__trace"),
+            &[statement_idx, var_id, type_id, value_ptr, value_size],
+            &[],
+            location,
+        )))
+    }
+
     /// Register if necessary, then invoke the `dict_alloc_new()` function.
     ///
     /// Returns a opaque pointer as the result.
@@ -0,0 +1,12 @@
+//! # Statement tracing
+//!
+//! When this metadata is present, the compiler reports every variable produced by a statement to
+//! the runtime's trace hook, by calling `cairo_native__trace` right after the statement's results
+//! have been produced and before jumping into the next statement.
+//!
+//! Every branch of an invocation is redirected through a block which stores each result in a stack
+//! slot and reports its statement index, variable id, type id and raw bytes.
+
+/// Statement tracing marker.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TraceStatementsMeta;
@@ -0,0 +1,25 @@
+//! # Trap analysis
+//!
+//! Every function call checks whether its callee trapped once it returns, which costs a call into
+//! the runtime. This metadata contains the functions which may trap, either by themselves or
+//! through one of their callees, so that calls to every other function can skip the check.
+
+use cairo_lang_sierra::ids::FunctionId;
+use std::collections::HashSet;
+
+/// The functions which may trap.
+#[derive(Clone, Debug, Default)]
+pub struct TrapAnalysisMeta {
+    may_trap: HashSet<FunctionId>,
+}
+
+impl TrapAnalysisMeta {
+    pub fn new(may_trap: HashSet<FunctionId>) -> Self {
+        Self { may_trap }
+    }
+
+    /// Return whether calling the function may trap.
+    pub fn may_trap(&self, function_id: &FunctionId) -> bool {
+        self.may_trap.contains(function_id)
+    }
+}
@@ -0,0 +1,162 @@
+//! # Compilation statistics
+//!
+//! A summary of what the compiler did for a given program, useful to check whether a program can
+//! run natively and where the compilation time goes. It's stored as metadata in the
+//! [NativeModule](crate::module::NativeModule) returned by the compiler.
+
+use crate::{compiler::find_unsupported_libfuncs, error::Error};
+use cairo_lang_sierra::{
+    extensions::core::{CoreLibfunc, CoreType},
+    program::{Program, Statement},
+    program_registry::ProgramRegistry,
+};
+use melior::ir::Operation;
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+/// Statistics about a program and its compilation.
+///
+/// The counts only depend on the program, so they can be collected with
+/// [from_program](Self::from_program) even when the program can't be compiled. The timings are
+/// filled in by [NativeContext::compile](crate::context::NativeContext::compile).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompilationStats {
+    /// Number of statements invoking each generic libfunc.
+    pub libfunc_counts: BTreeMap<String, usize>,
+    /// Generic libfuncs which the compiler doesn't support.
+    pub unsupported_libfuncs: Vec<String>,
+    /// Number of user functions in the program.
+    pub num_functions: usize,
+    /// Number of MLIR operations emitted, before running any pass.
+    pub num_operations: usize,
+    /// Time spent generating the MLIR module. It includes the types, libfuncs and statements times.
+    pub compilation_time: Duration,
+    /// Time spent building the MLIR types of the functions' signatures and blocks.
+    pub types_time: Duration,
+    /// Time spent in the libfunc builders.
+    pub libfuncs_time: Duration,
+    /// Time spent generating the rest of the functions' bodies, like the branches between
+    /// statements.
+    pub statements_time: Duration,
+    /// Time spent running the MLIR passes.
+    pub passes_time: Duration,
+}
+
+impl CompilationStats {
+    /// Collect the statistics which only depend on the program.
+    pub fn from_program(
+        program: &Program,
+        registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+    ) -> Result<Self, Error> {
+        let generic_names = program
+            .libfunc_declarations
+            .iter()
+            .map(|x| (&x.id, x.long_id.generic_id.0.to_string()))
+            .collect::<HashMap<_, _>>();
+
+        let mut libfunc_counts = BTreeMap::new();
+        for statement in &program.statements {
+            if let Statement::Invocation(invocation) = statement {
+                *libfunc_counts
+                    .entry(generic_names[&invocation.libfunc_id].clone())
+                    .or_default() += 1;
+            }
+        }
+
+        let mut unsupported_libfuncs = find_unsupported_libfuncs(program, registry)?
+            .into_iter()
+            .map(|x| x.generic_id.0.to_string())
+            .collect::<Vec<_>>();
+        unsupported_libfuncs.sort();
+        unsupported_libfuncs.dedup();
+
+        Ok(Self {
+            libfunc_counts,
+            unsupported_libfuncs,
+            num_functions: program.funcs.len(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Count an operation and all the operations nested within its regions.
+pub(crate) fn count_operations(operation: &Operation) -> usize {
+    let mut count = 1;
+    for region_idx in 0..operation.region_count() {
+        let Ok(region) = operation.region(region_idx) else {
+            continue;
+        };
+
+        let mut block = region.first_block();
+        while let Some(current_block) = block {
+            let mut nested = current_block.first_operation();
+            while let Some(current_op) = nested {
+                count += count_operations(&current_op);
+                nested = current_op.next_in_block();
+            }
+
+            block = current_block.next_in_region();
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{context::NativeContext, utils::test::load_cairo};
+    use cairo_lang_sierra::ProgramParser;
+
+    #[test]
+    fn compilation_stats() {
+        let (_, program) = load_cairo! {
+            fn run_test(a: felt252, b: felt252, c: felt252) -> felt252 {
+                a + b + c
+            }
+        };
+
+        let module = NativeContext::new().compile(&program, None).unwrap();
+        let stats = module.get_metadata::<CompilationStats>().unwrap();
+
+        assert_eq!(stats.libfunc_counts.get("felt252_add"), Some(&2));
+        assert!(stats.unsupported_libfuncs.is_empty());
+        assert_eq!(stats.num_functions, 1);
+        assert!(stats.num_operations > 0);
+        assert!(
+            stats.compilation_time
+                >= stats.types_time + stats.libfuncs_time + stats.statements_time
+        );
+    }
+
+    #[test]
+    fn compilation_stats_unsupported_libfuncs() {
+        let program = ProgramParser::new()
+            .parse(
+                r#"
+                    type GasBuiltin = GasBuiltin;
+
+                    libfunc redeposit_gas = redeposit_gas;
+                    libfunc store_temp<GasBuiltin> = store_temp<GasBuiltin>;
+
+                    store_temp<GasBuiltin>([0]) -> ([0]);
+                    redeposit_gas([0]) -> ([0]);
+                    return([0]);
+
+                    program::program::run_test@0([0]: GasBuiltin) -> (GasBuiltin);
+                "#,
+            )
+            .unwrap();
+
+        // The program can't be compiled, but its statistics can still be collected.
+        assert!(NativeContext::new().compile(&program, None).is_err());
+
+        let registry = ProgramRegistry::new(&program).unwrap();
+        let stats = CompilationStats::from_program(&program, &registry).unwrap();
+        assert_eq!(stats.unsupported_libfuncs, ["redeposit_gas"]);
+        assert_eq!(stats.libfunc_counts.get("redeposit_gas"), Some(&1));
+        assert_eq!(stats.libfunc_counts.get("store_temp"), Some(&1));
+    }
+}
@@ -22,11 +22,8 @@ use cairo_lang_sierra::{
     program_registry::ProgramRegistry,
 };
 use melior::{
-    dialect::{
-        llvm::{self, r#type::pointer},
-        ods,
-    },
-    ir::{r#type::IntegerType, Block, Location, Module, Type, Value},
+    dialect::{llvm::r#type::pointer, ods},
+    ir::{Block, Location, Module, Type, Value},
     Context,
 };
 use num_traits::Signed;
@@ -191,7 +188,7 @@ impl TypeBuilder for CoreTypeConcrete {
                 metadata,
                 WithSelf::new(self_ty, info),
             ),
-            Self::Const(_) => todo!(),
+            Self::Const(_) => Err(CoreTypeBuilderError::UnsupportedType("Const".to_string())),
             Self::EcOp(info) => self::ec_op::build(
                 context,
                 module,
@@ -332,7 +329,7 @@ impl TypeBuilder for CoreTypeConcrete {
                 metadata,
                 WithSelf::new(self_ty, info),
             ),
-            Self::Span(_) => todo!("implement span type"),
+            Self::Span(_) => Err(CoreTypeBuilderError::UnsupportedType("Span".to_string())),
             Self::SquashedFelt252Dict(info) => self::squashed_felt252_dict::build(
                 context,
                 module,
@@ -662,11 +659,7 @@ impl TypeBuilder for CoreTypeConcrete {
             CoreTypeConcrete::Sint64(_) => get_integer_layout(64),
             CoreTypeConcrete::Sint128(_) => get_integer_layout(128),
             CoreTypeConcrete::Bytes31(_) => get_integer_layout(248),
-            CoreTypeConcrete::BoundedInt(info) => get_integer_layout(
-                (info.range.lower.bits().max(info.range.upper.bits()) + 1)
-                    .try_into()
-                    .expect("should always fit u32"),
-            ),
+            CoreTypeConcrete::BoundedInt(_) => get_integer_layout(252),
             CoreTypeConcrete::Const(const_type) => {
                 registry.get_type(&const_type.inner_ty)?.layout(registry)?
             }
@@ -826,21 +819,8 @@ impl TypeBuilder for CoreTypeConcrete {
         Ok(match self {
             Self::Enum(info) => match &info.info.long_id.generic_args[0] {
                 GenericArg::UserType(id) if id == bool_user_type_id => {
-                    let tag = entry.const_int(context, location, 0, 1)?;
-
-                    let value = entry.append_op_result(llvm::undef(
-                        llvm::r#type::r#struct(
-                            context,
-                            &[
-                                IntegerType::new(context, 1).into(),
-                                llvm::r#type::array(IntegerType::new(context, 8).into(), 0),
-                            ],
-                            false,
-                        ),
-                        location,
-                    ))?;
-
-                    entry.insert_value(context, location, value, tag, 0)?
+                    // Booleans are C-style enums, which are represented by their tag alone.
+                    entry.const_int(context, location, 0, 1)?
                 }
                 _ => unimplemented!("unsupported dict value type"),
             },
@@ -386,6 +386,10 @@
 //! payload to keep everything consistent and the padding will have its own index, shifting every
 //! index below it by one. However all that's been ignored for documenting purposes.
 //!
+//! C-style enums (those where every variant's payload is zero-sized, like `bool`) are the exception
+//! to the rules above: since there's no payload to store, they are represented by just their
+//! discriminant.
+//!
 //! An MLIR type cannot be an enumeration (it doesn't exist), therefore a variant or a buffer has to
 //! be used. Using a buffer as a dummy payload has been discarded because it doesn't keep the enum's
 //! alignment information. To keep that info, the first variant with the biggest alignment is used
@@ -460,14 +464,7 @@ pub fn build<'ctx>(
             .iter()
             .all(|type_id| registry.get_type(type_id).unwrap().is_zst(registry)) =>
         {
-            llvm::r#type::r#struct(
-                context,
-                &[
-                    IntegerType::new(context, tag_bits).into(),
-                    llvm::r#type::array(i8_ty, 0),
-                ],
-                false,
-            )
+            IntegerType::new(context, tag_bits).into()
         }
         _ => llvm::r#type::r#struct(
             context,
@@ -547,7 +544,7 @@ mod test {
         program_registry::ProgramRegistry,
     };
     use melior::{
-        ir::{r#type::IntegerType, Location, Module},
+        ir::{r#type::IntegerType, Location, Module, Type},
         Context,
     };
 
@@ -580,4 +577,45 @@ mod test {
             })
             .any(|width| width == i0_ty);
     }
+
+    #[test]
+    fn enum_type_c_style_is_tag_only() {
+        let (_, program) = load_cairo! {
+            enum Color {
+                Red: (),
+                Green: (),
+                Blue: (),
+            }
+
+            fn run_program(x: Color) -> Color {
+                x
+            }
+        };
+
+        let context = Context::new();
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+
+        let module = Module::new(Location::unknown(&context));
+        let mut metadata = MetadataStorage::new();
+
+        let color_id = program
+            .type_declarations
+            .iter()
+            .find(|ty| {
+                ty.id
+                    .debug_name
+                    .as_deref()
+                    .is_some_and(|name| name.ends_with("Color"))
+            })
+            .map(|ty| &ty.id)
+            .unwrap();
+        let color_ty = registry
+            .get_type(color_id)
+            .unwrap()
+            .build(&context, &module, &registry, &mut metadata, color_id)
+            .unwrap();
+
+        let i2_ty: Type = IntegerType::new(&context, 2).into();
+        assert_eq!(color_ty, i2_ty);
+    }
 }
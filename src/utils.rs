@@ -111,6 +111,46 @@ pub fn cairo_to_sierra(program: &Path) -> Arc<Program> {
     }
 }
 
+/// Compile the cairo project at the given path to sierra.
+///
+/// The path may be either a single `.cairo` file or a directory containing a `cairo_project.toml`.
+/// When the compilation fails, the compiler diagnostics are returned verbatim in the error.
+pub fn compile_cairo_project(
+    path: &Path,
+    allow_warnings: bool,
+) -> Result<Program, crate::error::Error> {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .build()
+        .map_err(|e| crate::error::Error::CairoCompilation(e.to_string()))?;
+    let main_crate_ids = setup_project(&mut db, path)
+        .map_err(|e| crate::error::Error::CairoCompilation(e.to_string()))?;
+
+    let mut diagnostics = String::new();
+    let mut diagnostics_reporter = DiagnosticsReporter::write_to_string(&mut diagnostics);
+    if allow_warnings {
+        diagnostics_reporter = diagnostics_reporter.allow_warnings();
+    }
+
+    let program = compile_prepared_db(
+        &mut db,
+        main_crate_ids,
+        CompilerConfig {
+            diagnostics_reporter,
+            replace_ids: true,
+            ..Default::default()
+        },
+    );
+
+    program.map_err(|e| {
+        crate::error::Error::CairoCompilation(if diagnostics.is_empty() {
+            e.to_string()
+        } else {
+            diagnostics
+        })
+    })
+}
+
 pub fn cairo_to_sierra_with_debug_info<'ctx>(
     context: &'ctx Context,
     program: &Path,
@@ -238,9 +278,39 @@ pub fn create_engine(
 }
 
 pub fn run_pass_manager(context: &Context, module: &mut Module) -> Result<(), Error> {
+    run_pass_manager_with_options(context, module, OptLevel::None, None)
+}
+
+/// Optimize the module and lower it into the LLVM dialect.
+///
+/// The optimization passes are selected by `opt_level`, unless a textual `pass_pipeline` (for
+/// example `builtin.module(inline,canonicalize)`) is provided, in which case it's run instead. The
+/// lowering passes are always run afterwards.
+pub fn run_pass_manager_with_options(
+    context: &Context,
+    module: &mut Module,
+    opt_level: OptLevel,
+    pass_pipeline: Option<&str>,
+) -> Result<(), Error> {
     let pass_manager = PassManager::new(context);
     pass_manager.enable_verifier(true);
-    pass_manager.add_pass(pass::transform::create_canonicalizer());
+    match pass_pipeline {
+        Some(pass_pipeline) => {
+            pass::parse_pass_pipeline(pass_manager.as_operation_pass_manager(), pass_pipeline)?
+        }
+        None => {
+            if opt_level >= OptLevel::Default {
+                pass_manager.add_pass(pass::transform::create_inliner());
+            }
+            if opt_level >= OptLevel::Aggressive {
+                pass_manager.add_pass(pass::transform::create_sccp());
+            }
+            pass_manager.add_pass(pass::transform::create_canonicalizer());
+            if opt_level >= OptLevel::Less {
+                pass_manager.add_pass(pass::transform::create_cse());
+            }
+        }
+    }
     pass_manager.add_pass(pass::conversion::create_scf_to_control_flow());
     pass_manager.add_pass(pass::conversion::create_arith_to_llvm());
     pass_manager.add_pass(pass::conversion::create_control_flow_to_llvm());
@@ -311,6 +381,22 @@ pub fn register_runtime_symbols(engine: &ExecutionEngine) {
                 as *mut (),
         );
 
+        engine.register_symbol(
+            "cairo_native__trap",
+            cairo_native_runtime::cairo_native__trap as *const fn(u64) -> () as *mut (),
+        );
+
+        engine.register_symbol(
+            "cairo_native__peek_trap",
+            cairo_native_runtime::cairo_native__peek_trap as *const fn() -> u64 as *mut (),
+        );
+
+        engine.register_symbol(
+            "cairo_native__trace",
+            cairo_native_runtime::cairo_native__trace
+                as *const fn(u64, u64, u64, *const u8, u64) -> () as *mut (),
+        );
+
         engine.register_symbol(
             "cairo_native__dict_free",
             cairo_native_runtime::cairo_native__dict_free as *const fn(*mut std::ffi::c_void) -> ()
@@ -1065,4 +1151,39 @@ pub mod test {
             .keys()
             .any(|func| func.debug_name == Some("hello::hello::greet".into())));
     }
+
+    #[test]
+    fn run_program_without_debug_names() {
+        // Sierra extracted from contract classes has no debug names, only numeric ids.
+        let program = cairo_lang_sierra::ProgramParser::new()
+            .parse(
+                r#"
+                    type [0] = felt252;
+
+                    libfunc [0] = felt252_add;
+                    libfunc [1] = store_temp<[0]>;
+
+                    [0]([0], [1]) -> ([2]);
+                    [1]([2]) -> ([2]);
+                    return([2]);
+
+                    [0]@0([0]: [0], [1]: [0]) -> ([0]);
+                "#,
+            )
+            .unwrap();
+        assert!(program.funcs.iter().all(|x| x.id.debug_name.is_none()));
+        assert_eq!(generate_function_name(&program.funcs[0].id), "f0");
+
+        let module = NativeContext::new().compile(&program, None).unwrap();
+        let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+        let result = executor
+            .invoke_dynamic(
+                &FunctionId::new(0),
+                &[JitValue::Felt252(2.into()), JitValue::Felt252(3.into())],
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(result.return_value, JitValue::Felt252(5.into()));
+    }
 }
@@ -23,6 +23,9 @@ use num_traits::Euclid;
 use starknet_types_core::felt::Felt;
 use std::{alloc::Layout, collections::HashMap, ops::Neg, ptr::NonNull};
 
+#[cfg(feature = "with-serde")]
+pub(crate) mod json;
+
 /// A JitValue is a value that can be passed to the JIT engine as an argument or received as a result.
 ///
 /// They map to the cairo/sierra types.
@@ -31,7 +34,6 @@ use std::{alloc::Layout, collections::HashMap, ops::Neg, ptr::NonNull};
 ///
 /// A Boxed value or a non-null Nullable value is returned with it's inner value.
 #[derive(Debug, Clone, Educe)]
-#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 #[educe(Eq, PartialEq)]
 pub enum JitValue {
     Felt252(Felt),
@@ -76,7 +78,6 @@ pub enum JitValue {
     },
     BoundedInt {
         value: Felt,
-        #[cfg_attr(feature = "with-serde", serde(with = "range_serde"))]
         range: Range,
     },
     /// Used as return value for Nullables that are null.
@@ -204,7 +205,7 @@ impl JitValue {
                 } => {
                     let value = value.to_bigint();
 
-                    if lower < upper {
+                    if lower >= upper {
                         return Err(Error::Error("BoundedInt range is invalid".to_string()));
                     }
 
@@ -218,7 +219,7 @@ impl JitValue {
                                 "BoundedInt value is out of range".to_string(),
                             ));
                         }
-                    } else if !(upper > value && value >= lower) {
+                    } else if !(value >= lower || value < upper) {
                         return Err(Error::Error("BoundedInt value is out of range".to_string()));
                     }
 
@@ -1186,6 +1187,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_to_jit_bounded_int() {
+        let program = ProgramParser::new()
+            .parse("type BoundedInt = BoundedInt<-5, 5>;")
+            .unwrap();
+
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+        let to_jit = |value: i32| {
+            JitValue::BoundedInt {
+                value: Felt::from(value),
+                range: Range {
+                    lower: BigInt::from(-5),
+                    upper: BigInt::from(6),
+                },
+            }
+            .to_jit(&Bump::new(), &registry, &program.type_declarations[0].id)
+            .map(|ptr| unsafe { *ptr.cast::<[u8; 32]>().as_ptr() })
+        };
+
+        assert_eq!(to_jit(-5).unwrap(), Felt::from(-5).to_bytes_le());
+        assert_eq!(to_jit(0).unwrap(), Felt::ZERO.to_bytes_le());
+        assert_eq!(to_jit(5).unwrap(), Felt::from(5).to_bytes_le());
+        assert!(to_jit(-6).is_err());
+        assert!(to_jit(6).is_err());
+    }
+
     #[test]
     fn test_to_jit_ec_point() {
         let program = ProgramParser::new()
@@ -1383,84 +1410,109 @@ mod test {
             _ => panic!("Unexpected error type: {:?}", result),
         }
     }
-}
-
-#[cfg(feature = "with-serde")]
-mod range_serde {
-    use std::fmt;
-
-    use cairo_lang_sierra::extensions::utils::Range;
-    use serde::{
-        de::{self, Visitor},
-        ser::SerializeStruct,
-        Deserializer, Serializer,
-    };
-
-    pub fn serialize<S>(range: &Range, ser: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut state = ser.serialize_struct("Range", 2)?;
-
-        state.serialize_field("lower", &range.lower)?;
-        state.serialize_field("upper", &range.upper)?;
-
-        state.end()
-    }
 
-    pub fn deserialize<'de, D>(de: D) -> Result<Range, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct RangeVisitor;
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let value = JitValue::Struct {
+            fields: vec![
+                JitValue::Felt252(Felt::from(-1)),
+                JitValue::Uint128(u128::MAX),
+                JitValue::Array(vec![JitValue::Uint8(1), JitValue::Uint8(2)]),
+                JitValue::Enum {
+                    tag: 1,
+                    value: Box::new(JitValue::Sint32(-3)),
+                    debug_name: None,
+                },
+                JitValue::BoundedInt {
+                    value: Felt::from(3),
+                    range: Range {
+                        lower: BigInt::from(-5),
+                        upper: BigInt::from(5),
+                    },
+                },
+                JitValue::Null,
+                JitValue::Bytes31([0xAB; 31]),
+                JitValue::Felt252Dict {
+                    value: HashMap::from([(Felt::from(7), JitValue::Uint64(u64::MAX))]),
+                    debug_name: None,
+                },
+                JitValue::EcState(Felt::from(1), Felt::from(2), Felt::from(3), Felt::from(4)),
+                JitValue::Secp256R1Point {
+                    x: (u128::MAX, 1),
+                    y: (2, 3),
+                },
+                JitValue::Sint128(i128::MIN),
+            ],
+            debug_name: None,
+        };
 
-        impl<'de> Visitor<'de> for RangeVisitor {
-            type Value = Range;
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<JitValue>(&json).unwrap(), value);
+    }
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("an integer between -2^31 and 2^31")
-            }
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_serde_schema() {
+        let value = JitValue::Enum {
+            tag: 1,
+            value: Box::new(JitValue::Struct {
+                fields: vec![
+                    JitValue::Felt252(Felt::from(42)),
+                    JitValue::Uint64(1 << 53),
+                    JitValue::Uint64((1 << 53) - 1),
+                    JitValue::Sint64(-(1 << 53)),
+                ],
+                debug_name: None,
+            }),
+            debug_name: Some("Choice".to_string()),
+        };
 
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: de::SeqAccess<'de>,
-            {
-                let lower = seq
-                    .next_element()?
-                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                let upper = seq
-                    .next_element()?
-                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-
-                Ok(Range { lower, upper })
-            }
+        let json = serde_json::json!({
+            "enum": {
+                "variant": 1,
+                "payload": {
+                    "struct": {
+                        "fields": [
+                            { "felt252": "0x2a" },
+                            { "u64": "9007199254740992" },
+                            { "u64": 9007199254740991u64 },
+                            { "i64": "-9007199254740992" },
+                        ],
+                    },
+                },
+                "debug_name": "Choice",
+            },
+        });
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+        assert_eq!(serde_json::from_value::<JitValue>(json).unwrap(), value);
 
-            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-            where
-                A: de::MapAccess<'de>,
-            {
-                let mut lower = None;
-                let mut upper = None;
-
-                while let Some((field, value)) = map.next_entry()? {
-                    match field {
-                        "lower" => {
-                            lower = Some(value);
-                        }
-                        "upper" => {
-                            upper = Some(value);
-                        }
-                        _ => return Err(de::Error::unknown_field(field, &["lower", "upper"])),
-                    }
-                }
+        // Felts may also be written in decimal, and integers as strings.
+        assert_eq!(
+            serde_json::from_str::<JitValue>(r#"{"array": [{"felt252": "-1"}, {"u8": "7"}]}"#)
+                .unwrap(),
+            JitValue::Array(vec![JitValue::Felt252(Felt::from(-1)), JitValue::Uint8(7)])
+        );
+    }
 
-                Ok(Range {
-                    lower: lower.ok_or_else(|| de::Error::missing_field("lower"))?,
-                    upper: upper.ok_or_else(|| de::Error::missing_field("upper"))?,
-                })
-            }
-        }
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_serde_malformed_input() {
+        let error = |json: &str| {
+            serde_json::from_str::<JitValue>(json)
+                .unwrap_err()
+                .to_string()
+        };
 
-        de.deserialize_struct("Range", &["lower", "upper"], RangeVisitor)
+        assert!(error(r#"{"u8": 256}"#).starts_with("invalid value at `$`: 256 is not a valid u8"));
+        assert!(error(r#"{"struct": {"debug_name": null}}"#)
+            .starts_with("invalid value at `$`: missing field `fields`"));
+        assert!(error(r#"{"Uint8": 1}"#).starts_with("invalid value at `$`: unknown value type"));
+        assert!(error(
+            r#"{"struct": {"fields": [{"u8": 1}, {"enum": {"variant": 0, "payload": {"felt252": 1}}}]}}"#
+        )
+        .starts_with("invalid value at `$.fields[1].payload`: expected a felt252 string"));
+        assert!(error(r#"{"array": [{"felt252": "0xzz"}]}"#)
+            .starts_with("invalid value at `$[0]`: invalid felt252 `0xzz`"));
     }
 }
@@ -0,0 +1,498 @@
+//! # Stable JSON schema for [`JitValue`]
+//!
+//! Every value is an object with a single key that names its type:
+//!
+//! | Value                  | Schema                                                           |
+//! |------------------------|------------------------------------------------------------------|
+//! | `Felt252`              | `{"felt252": "0x2a"}`                                            |
+//! | `Bytes31`              | `{"bytes31": "0x0102…"}` (the 31 bytes in order)                 |
+//! | `Uint8` to `Sint128`   | `{"u8": 42}`, `{"i64": -3}`, `{"u128": "18446744073709551616"}`  |
+//! | `Array`                | `{"array": [...]}`                                               |
+//! | `Struct`               | `{"struct": {"fields": [...]}}`                                  |
+//! | `Enum`                 | `{"enum": {"variant": 1, "payload": ...}}`                       |
+//! | `Felt252Dict`          | `{"felt252_dict": {"entries": {"0x1": ...}}}`                    |
+//! | `EcPoint`              | `{"ec_point": ["0x1", "0x2"]}`                                   |
+//! | `EcState`              | `{"ec_state": ["0x1", "0x2", "0x3", "0x4"]}`                     |
+//! | `Secp256K1Point`       | `{"secp256k1_point": {"x": [lo, hi], "y": [lo, hi]}}`            |
+//! | `Secp256R1Point`       | `{"secp256r1_point": {"x": [lo, hi], "y": [lo, hi]}}`            |
+//! | `BoundedInt`           | `{"bounded_int": {"value": "0x3", "lower": -5, "upper": 5}}`     |
+//! | `Null`                 | `{"null": null}`                                                 |
+//!
+//! Felts are written as hexadecimal strings, but decimal strings (including negative ones) are
+//! also accepted. Integers are written as numbers unless they're beyond 2^53 - 1 in magnitude,
+//! which isn't exact in every JSON parser, in which case they're written as decimal strings. Both
+//! forms are accepted for every integer. Structs, enums and dictionaries may also have a
+//! `debug_name`, which is written for returned values and optional otherwise.
+//!
+//! Errors name the path of the invalid value, like `$.fields[2].payload`.
+
+use super::JitValue;
+use crate::types::felt252::PRIME;
+use cairo_lang_sierra::extensions::utils::Range;
+use num_bigint::{BigInt, BigUint, Sign};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use starknet_types_core::felt::Felt;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+/// Integers beyond this magnitude are written as strings.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+impl Serialize for JitValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        to_json(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JitValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        from_json(&Value::deserialize(deserializer)?, "$").map_err(de::Error::custom)
+    }
+}
+
+/// Serialize integers which may not fit in a double as strings, like [`JitValue`] does.
+pub(crate) mod integer {
+    use super::{integer_to_json, parse_integer};
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Display + TryInto<i64>,
+        S: Serializer,
+    {
+        integer_to_json(*value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        D: Deserializer<'de>,
+    {
+        parse_integer(&Value::deserialize(deserializer)?, "$").map_err(de::Error::custom)
+    }
+}
+
+/// Same as [`integer`], for optional integers.
+pub(crate) mod optional_integer {
+    use super::{integer_to_json, parse_integer};
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Display + TryInto<i64>,
+        S: Serializer,
+    {
+        value.map(integer_to_json).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::Null => Ok(None),
+            value => parse_integer(&value, "$")
+                .map(Some)
+                .map_err(de::Error::custom),
+        }
+    }
+}
+
+fn to_json(value: &JitValue) -> Value {
+    let (kind, value) = match value {
+        JitValue::Felt252(value) => ("felt252", felt_to_json(value)),
+        JitValue::Bytes31(value) => (
+            "bytes31",
+            Value::String(format!(
+                "0x{}",
+                value.iter().map(|x| format!("{x:02x}")).collect::<String>()
+            )),
+        ),
+        JitValue::Array(values) => ("array", values.iter().map(to_json).collect()),
+        JitValue::Struct { fields, debug_name } => (
+            "struct",
+            with_debug_name(
+                [("fields", fields.iter().map(to_json).collect())],
+                debug_name,
+            ),
+        ),
+        JitValue::Enum {
+            tag,
+            value,
+            debug_name,
+        } => (
+            "enum",
+            with_debug_name(
+                [("variant", Value::from(*tag)), ("payload", to_json(value))],
+                debug_name,
+            ),
+        ),
+        JitValue::Felt252Dict { value, debug_name } => {
+            let mut entries = value.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+
+            (
+                "felt252_dict",
+                with_debug_name(
+                    [(
+                        "entries",
+                        Value::Object(
+                            entries
+                                .into_iter()
+                                .map(|(key, value)| (felt_to_string(key), to_json(value)))
+                                .collect(),
+                        ),
+                    )],
+                    debug_name,
+                ),
+            )
+        }
+        JitValue::Uint8(value) => ("u8", integer_to_json(*value)),
+        JitValue::Uint16(value) => ("u16", integer_to_json(*value)),
+        JitValue::Uint32(value) => ("u32", integer_to_json(*value)),
+        JitValue::Uint64(value) => ("u64", integer_to_json(*value)),
+        JitValue::Uint128(value) => ("u128", integer_to_json(*value)),
+        JitValue::Sint8(value) => ("i8", integer_to_json(*value)),
+        JitValue::Sint16(value) => ("i16", integer_to_json(*value)),
+        JitValue::Sint32(value) => ("i32", integer_to_json(*value)),
+        JitValue::Sint64(value) => ("i64", integer_to_json(*value)),
+        JitValue::Sint128(value) => ("i128", integer_to_json(*value)),
+        JitValue::EcPoint(x, y) => ("ec_point", [x, y].into_iter().map(felt_to_json).collect()),
+        JitValue::EcState(x0, y0, x1, y1) => (
+            "ec_state",
+            [x0, y0, x1, y1].into_iter().map(felt_to_json).collect(),
+        ),
+        JitValue::Secp256K1Point { x, y } => ("secp256k1_point", secp256_point_to_json(x, y)),
+        JitValue::Secp256R1Point { x, y } => ("secp256r1_point", secp256_point_to_json(x, y)),
+        JitValue::BoundedInt { value, range } => (
+            "bounded_int",
+            Value::Object(Map::from_iter([
+                ("value".to_string(), felt_to_json(value)),
+                ("lower".to_string(), big_integer_to_json(&range.lower)),
+                ("upper".to_string(), big_integer_to_json(&range.upper)),
+            ])),
+        ),
+        JitValue::Null => ("null", Value::Null),
+    };
+
+    Value::Object(Map::from_iter([(kind.to_string(), value)]))
+}
+
+fn with_debug_name<const N: usize>(
+    fields: [(&str, Value); N],
+    debug_name: &Option<String>,
+) -> Value {
+    let mut object = fields
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect::<Map<_, _>>();
+    if let Some(debug_name) = debug_name {
+        object.insert("debug_name".to_string(), Value::String(debug_name.clone()));
+    }
+
+    Value::Object(object)
+}
+
+fn felt_to_string(value: &Felt) -> String {
+    format!("{:#x}", value.to_bigint())
+}
+
+fn felt_to_json(value: &Felt) -> Value {
+    Value::String(felt_to_string(value))
+}
+
+fn integer_to_json<T>(value: T) -> Value
+where
+    T: Copy + Display + TryInto<i64>,
+{
+    match value.try_into() {
+        Ok(x) if x.unsigned_abs() <= MAX_SAFE_INTEGER => Value::from(x),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn big_integer_to_json(value: &BigInt) -> Value {
+    match i64::try_from(value) {
+        Ok(x) if x.unsigned_abs() <= MAX_SAFE_INTEGER => Value::from(x),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn secp256_point_to_json(x: &(u128, u128), y: &(u128, u128)) -> Value {
+    Value::Object(Map::from_iter([
+        (
+            "x".to_string(),
+            Value::Array(vec![integer_to_json(x.0), integer_to_json(x.1)]),
+        ),
+        (
+            "y".to_string(),
+            Value::Array(vec![integer_to_json(y.0), integer_to_json(y.1)]),
+        ),
+    ]))
+}
+
+fn error(path: &str, message: impl Display) -> String {
+    format!("invalid value at `{path}`: {message}")
+}
+
+fn from_json(value: &Value, path: &str) -> Result<JitValue, String> {
+    let object = match value {
+        Value::Object(object) if object.len() == 1 => object,
+        _ => return Err(error(path, "expected an object with a single type key")),
+    };
+    let (kind, value) = object.iter().next().unwrap();
+
+    Ok(match kind.as_str() {
+        "felt252" => JitValue::Felt252(parse_felt(value, path)?),
+        "bytes31" => JitValue::Bytes31(parse_bytes31(value, path)?),
+        "array" => JitValue::Array(
+            parse_array(value, path)?
+                .iter()
+                .enumerate()
+                .map(|(i, value)| from_json(value, &format!("{path}[{i}]")))
+                .collect::<Result<_, _>>()?,
+        ),
+        "struct" => {
+            let object = parse_object(value, path, &["fields", "debug_name"])?;
+            let fields_path = format!("{path}.fields");
+
+            JitValue::Struct {
+                fields: parse_array(get_field(object, "fields", path)?, &fields_path)?
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| from_json(value, &format!("{fields_path}[{i}]")))
+                    .collect::<Result<_, _>>()?,
+                debug_name: parse_debug_name(object, path)?,
+            }
+        }
+        "enum" => {
+            let object = parse_object(value, path, &["variant", "payload", "debug_name"])?;
+
+            JitValue::Enum {
+                tag: parse_integer(
+                    get_field(object, "variant", path)?,
+                    &format!("{path}.variant"),
+                )?,
+                value: Box::new(from_json(
+                    get_field(object, "payload", path)?,
+                    &format!("{path}.payload"),
+                )?),
+                debug_name: parse_debug_name(object, path)?,
+            }
+        }
+        "felt252_dict" => {
+            let object = parse_object(value, path, &["entries", "debug_name"])?;
+            let entries_path = format!("{path}.entries");
+            let Value::Object(entries) = get_field(object, "entries", path)? else {
+                return Err(error(&entries_path, "expected an object"));
+            };
+
+            JitValue::Felt252Dict {
+                value: entries
+                    .iter()
+                    .map(|(key, value)| {
+                        let path = format!("{entries_path}[{key}]");
+                        let key = parse_felt_str(key)
+                            .ok_or_else(|| error(&path, format!("invalid felt252 key `{key}`")))?;
+
+                        Ok((key, from_json(value, &path)?))
+                    })
+                    .collect::<Result<HashMap<_, _>, String>>()?,
+                debug_name: parse_debug_name(object, path)?,
+            }
+        }
+        "u8" => JitValue::Uint8(parse_integer(value, path)?),
+        "u16" => JitValue::Uint16(parse_integer(value, path)?),
+        "u32" => JitValue::Uint32(parse_integer(value, path)?),
+        "u64" => JitValue::Uint64(parse_integer(value, path)?),
+        "u128" => JitValue::Uint128(parse_integer(value, path)?),
+        "i8" => JitValue::Sint8(parse_integer(value, path)?),
+        "i16" => JitValue::Sint16(parse_integer(value, path)?),
+        "i32" => JitValue::Sint32(parse_integer(value, path)?),
+        "i64" => JitValue::Sint64(parse_integer(value, path)?),
+        "i128" => JitValue::Sint128(parse_integer(value, path)?),
+        "ec_point" => {
+            let [x, y] = parse_felts(value, path)?;
+            JitValue::EcPoint(x, y)
+        }
+        "ec_state" => {
+            let [x0, y0, x1, y1] = parse_felts(value, path)?;
+            JitValue::EcState(x0, y0, x1, y1)
+        }
+        "secp256k1_point" => {
+            let (x, y) = parse_secp256_point(value, path)?;
+            JitValue::Secp256K1Point { x, y }
+        }
+        "secp256r1_point" => {
+            let (x, y) = parse_secp256_point(value, path)?;
+            JitValue::Secp256R1Point { x, y }
+        }
+        "bounded_int" => {
+            let object = parse_object(value, path, &["value", "lower", "upper"])?;
+
+            JitValue::BoundedInt {
+                value: parse_felt(get_field(object, "value", path)?, &format!("{path}.value"))?,
+                range: Range {
+                    lower: parse_integer(
+                        get_field(object, "lower", path)?,
+                        &format!("{path}.lower"),
+                    )?,
+                    upper: parse_integer(
+                        get_field(object, "upper", path)?,
+                        &format!("{path}.upper"),
+                    )?,
+                },
+            }
+        }
+        "null" => match value {
+            Value::Null => JitValue::Null,
+            _ => return Err(error(path, "expected null")),
+        },
+        _ => return Err(error(path, format!("unknown value type `{kind}`"))),
+    })
+}
+
+fn parse_object<'a>(
+    value: &'a Value,
+    path: &str,
+    keys: &[&str],
+) -> Result<&'a Map<String, Value>, String> {
+    let Value::Object(object) = value else {
+        return Err(error(path, "expected an object"));
+    };
+    if let Some(key) = object.keys().find(|key| !keys.contains(&key.as_str())) {
+        return Err(error(path, format!("unknown field `{key}`")));
+    }
+
+    Ok(object)
+}
+
+fn get_field<'a>(
+    object: &'a Map<String, Value>,
+    key: &str,
+    path: &str,
+) -> Result<&'a Value, String> {
+    object
+        .get(key)
+        .ok_or_else(|| error(path, format!("missing field `{key}`")))
+}
+
+fn parse_debug_name(object: &Map<String, Value>, path: &str) -> Result<Option<String>, String> {
+    match object.get("debug_name") {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(debug_name)) => Ok(Some(debug_name.clone())),
+        Some(_) => Err(error(&format!("{path}.debug_name"), "expected a string")),
+    }
+}
+
+fn parse_array<'a>(value: &'a Value, path: &str) -> Result<&'a [Value], String> {
+    match value {
+        Value::Array(values) => Ok(values),
+        _ => Err(error(path, "expected an array")),
+    }
+}
+
+fn parse_integer<T>(value: &Value, path: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    let text = match value {
+        Value::Number(value) => value.to_string(),
+        Value::String(value) => value.clone(),
+        _ => return Err(error(path, "expected an integer")),
+    };
+
+    text.parse()
+        .map_err(|_| error(path, format!("{text} is not a valid {}", type_name::<T>())))
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn parse_felt_str(value: &str) -> Option<Felt> {
+    let value = match value.strip_prefix("0x") {
+        Some(digits) => BigInt::from(BigUint::parse_bytes(digits.as_bytes(), 16)?),
+        None => value.parse::<BigInt>().ok()?,
+    };
+    if value.magnitude() >= &*PRIME {
+        return None;
+    }
+
+    Some(Felt::from(&match value.sign() {
+        Sign::Minus => &*PRIME - value.magnitude(),
+        _ => value.magnitude().clone(),
+    }))
+}
+
+fn parse_felt(value: &Value, path: &str) -> Result<Felt, String> {
+    let Value::String(value) = value else {
+        return Err(error(path, "expected a felt252 string"));
+    };
+
+    parse_felt_str(value).ok_or_else(|| error(path, format!("invalid felt252 `{value}`")))
+}
+
+fn parse_felts<const N: usize>(value: &Value, path: &str) -> Result<[Felt; N], String> {
+    let values = parse_array(value, path)?;
+    if values.len() != N {
+        return Err(error(path, format!("expected {N} felts")));
+    }
+
+    let mut felts = [Felt::ZERO; N];
+    for (i, (felt, value)) in felts.iter_mut().zip(values).enumerate() {
+        *felt = parse_felt(value, &format!("{path}[{i}]"))?;
+    }
+
+    Ok(felts)
+}
+
+fn parse_bytes31(value: &Value, path: &str) -> Result<[u8; 31], String> {
+    let Value::String(value) = value else {
+        return Err(error(path, "expected a hexadecimal string"));
+    };
+    let invalid = || error(path, format!("`{value}` is not 31 hexadecimal bytes"));
+
+    let digits = value.strip_prefix("0x").ok_or_else(invalid)?;
+    if digits.len() != 62 || !digits.is_ascii() {
+        return Err(invalid());
+    }
+
+    let mut bytes = [0; 31];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+
+    Ok(bytes)
+}
+
+fn parse_secp256_point(value: &Value, path: &str) -> Result<((u128, u128), (u128, u128)), String> {
+    let object = parse_object(value, path, &["x", "y"])?;
+    let parse_coordinate = |key: &str| {
+        let coordinate_path = format!("{path}.{key}");
+        match parse_array(get_field(object, key, path)?, &coordinate_path)? {
+            [lo, hi] => Ok((
+                parse_integer(lo, &format!("{coordinate_path}[0]"))?,
+                parse_integer(hi, &format!("{coordinate_path}[1]"))?,
+            )),
+            _ => Err(error(
+                &coordinate_path,
+                "expected the low and high 128 bits",
+            )),
+        }
+    };
+
+    Ok((parse_coordinate("x")?, parse_coordinate("y")?))
+}
@@ -0,0 +1,25 @@
+use core::nullable::{nullable_from_box, match_nullable, FromNullableResult};
+use core::dict::Felt252DictEntryTrait;
+
+fn get_span(ref d: Felt252Dict<Nullable<Span<felt252>>>, key: felt252) -> Span<felt252> {
+    let (entry, value) = d.entry(key);
+    let span = match match_nullable(value) {
+        FromNullableResult::Null(()) => array![].span(),
+        FromNullableResult::NotNull(value) => value.unbox(),
+    };
+    d = entry.finalize(nullable_from_box(BoxTrait::new(span)));
+    span
+}
+
+fn main() -> (u32, felt252, felt252, u32, felt252, u32) {
+    let mut d: Felt252Dict<Nullable<Span<felt252>>> = Default::default();
+
+    d.insert(0, nullable_from_box(BoxTrait::new(array![8, 9, 10].span())));
+    d.insert(1, nullable_from_box(BoxTrait::new(array![20].span())));
+
+    let first = get_span(ref d, 0);
+    let second = get_span(ref d, 1);
+    let missing = get_span(ref d, 2);
+
+    (first.len(), *first.at(0), *first.at(2), second.len(), *second.at(0), missing.len())
+}
@@ -0,0 +1,8 @@
+use core::poseidon::{hades_permutation, poseidon_hash_span};
+
+fn main() -> ((felt252, felt252, felt252), felt252) {
+    (
+        hades_permutation(0, 0, 0),
+        poseidon_hash_span(array![1, 2, 3].span()),
+    )
+}
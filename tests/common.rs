@@ -395,24 +395,77 @@ pub fn run_vm_contract(
 #[track_caller]
 pub fn compare_inputless_program(program_path: &str) {
     let program: (String, Program, SierraCasmRunner) = load_cairo_path(program_path);
-    let program = &program;
+    run_both(&program, "main", &[]);
+}
+
+/// Runs an entry point using both the VM and cairo-native, and asserts that they return the same
+/// values. Arguments are converted to their VM representation, which is only implemented for felts
+/// and integers.
+///
+/// Programs using libfuncs which aren't supported by cairo-native yet are skipped, so that new
+/// programs can be added before they're supported.
+#[track_caller]
+pub fn run_both(
+    program: &(String, Program, SierraCasmRunner),
+    entry_point: &str,
+    args: &[JitValue],
+) {
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program.1).unwrap();
+    let unsupported = cairo_native::find_unsupported_libfuncs(&program.1, &registry).unwrap();
+    if !unsupported.is_empty() {
+        eprintln!(
+            "skipping {}: it uses unsupported libfuncs {:?}",
+            program.0,
+            unsupported
+                .iter()
+                .map(|x| x.generic_id.to_string())
+                .collect::<Vec<_>>()
+        );
+        return;
+    }
+
+    let vm_args = args
+        .iter()
+        .map(|arg| {
+            Arg::Value(match arg {
+                JitValue::Felt252(x) => Felt252::from_bytes_be(&x.to_bytes_be()),
+                JitValue::Uint8(x) => Felt252::from(*x),
+                JitValue::Uint16(x) => Felt252::from(*x),
+                JitValue::Uint32(x) => Felt252::from(*x),
+                JitValue::Uint64(x) => Felt252::from(*x),
+                JitValue::Uint128(x) => Felt252::from(*x),
+                JitValue::Sint8(x) => Felt252::from(*x),
+                JitValue::Sint16(x) => Felt252::from(*x),
+                JitValue::Sint32(x) => Felt252::from(*x),
+                JitValue::Sint64(x) => Felt252::from(*x),
+                JitValue::Sint128(x) => Felt252::from(*x),
+                _ => unimplemented!("VM argument conversion for {arg:?}"),
+            })
+        })
+        .collect::<Vec<_>>();
 
-    let result_vm = run_vm_program(program, "main", &[], Some(DEFAULT_GAS as usize)).unwrap();
+    let result_vm =
+        run_vm_program(program, entry_point, &vm_args, Some(DEFAULT_GAS as usize)).unwrap();
     let result_native = run_native_program(
         program,
-        "main",
-        &[],
+        entry_point,
+        args,
         Some(DEFAULT_GAS as u128),
         Option::<DummySyscallHandler>::None,
     );
 
     compare_outputs(
         &program.1,
-        &program.2.find_function("main").unwrap().id,
+        &program.2.find_function(entry_point).unwrap().id,
         &result_vm,
         &result_native,
     )
-    .expect("compare error");
+    .unwrap_or_else(|e| {
+        panic!(
+            "compare error in {}: {e}\nvm: {:?}\nnative: {:?}",
+            program.0, result_vm.value, result_native.return_value
+        )
+    });
 }
 
 /// Runs the program using cairo-native JIT.
@@ -1,4 +1,6 @@
-use crate::common::{compare_outputs, load_cairo, run_native_program, run_vm_program, DEFAULT_GAS};
+use crate::common::{
+    compare_outputs, load_cairo, run_both, run_native_program, run_vm_program, DEFAULT_GAS,
+};
 use cairo_felt::Felt252 as DeprecatedFelt;
 use cairo_lang_runner::{Arg, SierraCasmRunner};
 use cairo_lang_sierra::program::Program;
@@ -111,53 +113,8 @@ lazy_static! {
 #[test]
 fn felt252_to_bool_bug() {
     let program = &FELT252_TO_BOOL;
-    let a = true;
-    let result_vm = run_vm_program(
-        program,
-        "run_test",
-        &[Arg::Value(DeprecatedFelt::from(a))],
-        Some(DEFAULT_GAS as usize),
-    )
-    .unwrap();
-    let result_native = run_native_program(
-        program,
-        "run_test",
-        &[JitValue::Felt252(a.into())],
-        Some(DEFAULT_GAS as u128),
-        Option::<DummySyscallHandler>::None,
-    );
-
-    compare_outputs(
-        &program.1,
-        &program.2.find_function("run_test").unwrap().id,
-        &result_vm,
-        &result_native,
-    )
-    .unwrap();
-
-    let a = false;
-    let result_vm = run_vm_program(
-        program,
-        "run_test",
-        &[Arg::Value(DeprecatedFelt::from(a))],
-        Some(DEFAULT_GAS as usize),
-    )
-    .unwrap();
-    let result_native = run_native_program(
-        program,
-        "run_test",
-        &[JitValue::Felt252(a.into())],
-        Some(DEFAULT_GAS as u128),
-        Option::<DummySyscallHandler>::None,
-    );
-
-    compare_outputs(
-        &program.1,
-        &program.2.find_function("run_test").unwrap().id,
-        &result_vm,
-        &result_native,
-    )
-    .unwrap();
+    run_both(program, "run_test", &[JitValue::Felt252(true.into())]);
+    run_both(program, "run_test", &[JitValue::Felt252(false.into())]);
 }
 
 proptest! {
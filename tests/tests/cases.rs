@@ -22,6 +22,7 @@ use test_case::test_case;
 #[test_case("tests/cases/pedersen_hash.cairo")]
 #[test_case("tests/cases/unwrap_non_zero.cairo")]
 #[test_case("tests/cases/poseidon.cairo")]
+#[test_case("tests/cases/poseidon_hash_span.cairo")]
 #[test_case("tests/cases/panic_array.cairo")]
 #[test_case("tests/cases/generic_fn_loop.cairo")]
 // enums
@@ -109,6 +110,7 @@ use test_case::test_case;
 #[test_case("tests/cases/array/slice.cairo")]
 // nullable
 #[test_case("tests/cases/nullable/test_nullable.cairo")]
+#[test_case("tests/cases/nullable/dict_of_spans.cairo")]
 // Programs copied from the cairo-vm
 // https://github.com/lambdaclass/cairo-vm/tree/main/cairo_programs/cairo-1-programs
 #[test_case("tests/cases/cairo_vm/programs/array_append.cairo")]
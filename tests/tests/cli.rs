@@ -0,0 +1,81 @@
+use cairo_native::{execution_result::ExecutionResult, values::JitValue};
+use starknet_types_core::felt::Felt;
+use std::{fs, process::Command};
+
+#[test]
+fn run_json_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let program_path = dir.path().join("program.cairo");
+    let args_path = dir.path().join("args.json");
+    let output_path = dir.path().join("output.json");
+
+    fs::write(
+        &program_path,
+        r#"
+            #[derive(Drop)]
+            enum Choice {
+                A: u8,
+                B: (u128, felt252),
+            }
+
+            fn main(a: felt252, b: u128, c: Choice) -> (felt252, u128, Choice) {
+                (a, b, c)
+            }
+        "#,
+    )
+    .unwrap();
+    fs::write(
+        &args_path,
+        r#"[
+            {"felt252": "-1"},
+            {"u128": "340282366920938463463374607431768211455"},
+            {"enum": {"variant": 1, "payload": {"struct": {"fields": [
+                {"u128": 7},
+                {"felt252": "0x2a"}
+            ]}}}}
+        ]"#,
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_cairo-native-run"))
+        .arg(&program_path)
+        .arg("--single-file")
+        .arg("--args-file")
+        .arg(&args_path)
+        .arg("--output")
+        .arg(&output_path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = fs::read_to_string(&output_path).unwrap();
+    let result: ExecutionResult = serde_json::from_str(&output).unwrap();
+    assert_eq!(
+        result.return_value,
+        JitValue::Struct {
+            fields: vec![
+                JitValue::Felt252(Felt::from(-1)),
+                JitValue::Uint128(u128::MAX),
+                JitValue::Enum {
+                    tag: 1,
+                    value: Box::new(JitValue::Struct {
+                        fields: vec![JitValue::Uint128(7), JitValue::Felt252(Felt::from(42))],
+                        debug_name: None,
+                    }),
+                    debug_name: None,
+                },
+            ],
+            debug_name: None,
+        }
+    );
+
+    // The output uses the same schema as the arguments.
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let fields = &output["return_value"]["struct"]["fields"];
+    assert_eq!(
+        fields[0]["felt252"],
+        "0x800000000000011000000000000000000000000000000000000000000000000"
+    );
+    assert_eq!(fields[1]["u128"], "340282366920938463463374607431768211455");
+    assert_eq!(fields[2]["enum"]["variant"], 1);
+}
@@ -1,9 +1,19 @@
-use crate::common::load_cairo;
+use crate::common::{load_cairo, DEFAULT_GAS};
 use cairo_lang_sierra::{
     extensions::core::{CoreLibfunc, CoreType},
+    program::StatementIdx,
     program_registry::ProgramRegistry,
+    ProgramParser,
+};
+use cairo_native::{
+    context::NativeContext,
+    error::Error as NativeError,
+    executor::JitNativeExecutor,
+    metadata::{runtime_bindings::RuntimeBindingsMeta, MetadataStorage},
+    utils::{compile_cairo_project, find_function_id},
+    values::JitValue,
+    OptLevel,
 };
-use cairo_native::metadata::{runtime_bindings::RuntimeBindingsMeta, MetadataStorage};
 use melior::{
     dialect::DialectRegistry,
     ir::{Location, Module},
@@ -11,8 +21,8 @@ use melior::{
     utility::{register_all_dialects, register_all_llvm_translations},
     Context,
 };
-use std::error::Error;
-use tempfile::NamedTempFile;
+use std::{error::Error, fs, path::Path};
+use tempfile::{tempdir, NamedTempFile};
 
 #[test]
 pub fn compile_library() -> Result<(), Box<dyn Error>> {
@@ -71,3 +81,213 @@ pub fn compile_library() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+pub fn compile_entry_points_skips_unreachable() -> Result<(), Box<dyn Error>> {
+    let context = Context::new();
+
+    let program = load_cairo! {
+        #[inline(never)]
+        fn helper(value: felt252) -> felt252 {
+            value * 2
+        }
+
+        fn unused(value: felt252) -> felt252 {
+            value * 3
+        }
+
+        fn run_test(value: felt252) -> felt252 {
+            helper(value)
+        }
+    };
+
+    context.append_dialect_registry(&{
+        let registry = DialectRegistry::new();
+        register_all_dialects(&registry);
+        registry
+    });
+    context.load_all_available_dialects();
+
+    let module = Module::new(Location::unknown(&context));
+    let mut metadata = MetadataStorage::new();
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program.1)?;
+
+    metadata.insert(RuntimeBindingsMeta::default()).unwrap();
+
+    let entry_point = program
+        .1
+        .funcs
+        .iter()
+        .find(|f| {
+            f.id.debug_name
+                .as_deref()
+                .is_some_and(|name| name.ends_with("::run_test"))
+        })
+        .unwrap()
+        .id
+        .clone();
+
+    cairo_native::compile_entry_points(
+        &context,
+        &module,
+        &program.1,
+        &registry,
+        &mut metadata,
+        None,
+        &[entry_point],
+    )?;
+
+    let mlir = module.as_operation().to_string();
+    assert!(mlir.contains("::run_test"));
+    assert!(mlir.contains("::helper"));
+    assert!(!mlir.contains("::unused"));
+
+    Ok(())
+}
+
+#[test]
+pub fn find_unsupported_libfuncs() -> Result<(), Box<dyn Error>> {
+    let program = ProgramParser::new()
+        .parse(
+            r#"
+                type GasBuiltin = GasBuiltin;
+
+                libfunc redeposit_gas = redeposit_gas;
+                libfunc store_temp<GasBuiltin> = store_temp<GasBuiltin>;
+
+                redeposit_gas([0]) -> ([0]);
+                store_temp<GasBuiltin>([0]) -> ([0]);
+                return([0]);
+
+                program::program::run_test@0([0]: GasBuiltin) -> (GasBuiltin);
+            "#,
+        )
+        .unwrap();
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program)?;
+
+    let unsupported = cairo_native::find_unsupported_libfuncs(&program, &registry)?;
+    assert_eq!(unsupported.len(), 1);
+    assert_eq!(unsupported[0].generic_id.0, "redeposit_gas");
+    assert!(unsupported[0].generic_args.is_empty());
+    assert_eq!(unsupported[0].statements, [StatementIdx(0)]);
+
+    let error = NativeContext::new().compile(&program, None).unwrap_err();
+    assert!(error.to_string().contains("redeposit_gas"));
+
+    Ok(())
+}
+
+#[test]
+pub fn find_unsupported_libfuncs_matches_builders() -> Result<(), Box<dyn Error>> {
+    let redeposit_gas = ProgramParser::new()
+        .parse(
+            r#"
+                type GasBuiltin = GasBuiltin;
+
+                libfunc redeposit_gas = redeposit_gas;
+                libfunc store_temp<GasBuiltin> = store_temp<GasBuiltin>;
+
+                redeposit_gas([0]) -> ([0]);
+                store_temp<GasBuiltin>([0]) -> ([0]);
+                return([0]);
+
+                program::program::run_test@0([0]: GasBuiltin) -> (GasBuiltin);
+            "#,
+        )
+        .unwrap();
+    let programs = [
+        redeposit_gas,
+        compile_cairo_project(Path::new("tests/cases/fib_local.cairo"), false)?,
+        compile_cairo_project(Path::new("tests/cases/brainfuck.cairo"), false)?,
+        compile_cairo_project(Path::new("tests/cases/pedersen_hash.cairo"), false)?,
+        compile_cairo_project(Path::new("tests/cases/poseidon.cairo"), false)?,
+    ];
+
+    // Every libfunc reported as unsupported must fail to build, and every other one must build.
+    for program in &programs {
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
+        let unsupported = cairo_native::find_unsupported_libfuncs(program, &registry)?;
+
+        match NativeContext::new().compile(program, None) {
+            Ok(_) => assert!(unsupported.is_empty()),
+            Err(NativeError::LibfuncBuild { name, source, .. }) => {
+                assert!(matches!(*source, NativeError::UnsupportedLibfunc(_)));
+                assert!(unsupported
+                    .iter()
+                    .any(|libfunc| libfunc.id.to_string() == name));
+            }
+            Err(error) => panic!("unexpected error: {error}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+pub fn compile_cairo_project_single_file() -> Result<(), Box<dyn Error>> {
+    let program = compile_cairo_project(Path::new("tests/cases/fib_local.cairo"), false)?;
+    let entry_point = find_function_id(&program, "fib_local::fib_local::main");
+
+    let context = NativeContext::new();
+    let module = context.compile(&program, None)?;
+    let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+    let result = executor.invoke_dynamic(entry_point, &[], Some(DEFAULT_GAS as u128))?;
+
+    assert_eq!(
+        result.into_result()?,
+        [JitValue::Struct {
+            fields: vec![
+                JitValue::Felt252(1.into()),
+                JitValue::Felt252(1.into()),
+                JitValue::Felt252(89.into()),
+            ],
+            debug_name: None,
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn compile_cairo_project_directory() -> Result<(), Box<dyn Error>> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("cairo_project.toml"),
+        "[crate_roots]\nmy_project = \"src\"\n",
+    )?;
+    fs::create_dir(dir.path().join("src"))?;
+    fs::write(dir.path().join("src/lib.cairo"), "mod math;\n")?;
+    fs::write(
+        dir.path().join("src/math.cairo"),
+        "fn double(x: felt252) -> felt252 {\n    x * 2\n}\n",
+    )?;
+
+    let program = compile_cairo_project(dir.path(), false)?;
+    let entry_point = find_function_id(&program, "my_project::math::double");
+
+    let context = NativeContext::new();
+    let module = context.compile(&program, None)?;
+    let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+    let result = executor.invoke_dynamic(entry_point, &[JitValue::Felt252(21.into())], None)?;
+
+    assert_eq!(result.return_value, JitValue::Felt252(42.into()));
+
+    Ok(())
+}
+
+#[test]
+pub fn compile_cairo_project_forwards_diagnostics() -> Result<(), Box<dyn Error>> {
+    let dir = tempdir()?;
+    let path = dir.path().join("program.cairo");
+    fs::write(&path, "fn main() -> felt252 {\n    undefined_value\n}\n")?;
+
+    match compile_cairo_project(&path, false) {
+        Err(NativeError::CairoCompilation(diagnostics)) => {
+            assert!(diagnostics.contains("error"));
+            assert!(diagnostics.contains("undefined_value"));
+        }
+        other => panic!("expected a compilation error, got {other:?}"),
+    }
+
+    Ok(())
+}
@@ -114,3 +114,84 @@ fn enum_match() {
     )
     .unwrap();
 }
+
+#[test]
+fn builtins_passthrough() {
+    let program = load_cairo! {
+        use core::pedersen::pedersen;
+        use core::poseidon::hades_permutation;
+
+        fn run_test() -> (u128, u128, felt252, (felt252, felt252, felt252)) {
+            let lhs = 0xF0F0_u128;
+            let rhs = 0x0FF0_u128;
+            (lhs + rhs, lhs & rhs, pedersen(1, 2), hades_permutation(1, 2, 3))
+        }
+    };
+
+    let function = program.2.find_function("run_test").unwrap();
+    let builtins = function
+        .signature
+        .param_types
+        .iter()
+        .filter_map(|ty| {
+            program
+                .1
+                .type_declarations
+                .iter()
+                .find(|decl| &decl.id == ty)
+                .map(|decl| decl.long_id.generic_id.0.as_str())
+        })
+        .collect::<Vec<_>>();
+    for builtin in ["RangeCheck", "Pedersen", "Bitwise", "Poseidon"] {
+        assert!(builtins.contains(&builtin), "missing builtin {builtin}");
+    }
+
+    let result_vm = run_vm_program(&program, "run_test", &[], None).unwrap();
+    let result_native = run_native_program(
+        &program,
+        "run_test",
+        &[],
+        None,
+        Option::<DummySyscallHandler>::None,
+    );
+
+    compare_outputs(&program.1, &function.id, &result_vm, &result_native).unwrap();
+}
+
+#[test]
+fn withdraw_gas_exhaustion() {
+    let program = load_cairo! {
+        use core::gas::withdraw_gas;
+
+        fn run_test() -> u32 {
+            let mut iterations = 0_u32;
+            loop {
+                match withdraw_gas() {
+                    Option::Some(()) => iterations += 1,
+                    Option::None(()) => { break; },
+                };
+            };
+            iterations
+        }
+    };
+
+    // The loop must stop at the same iteration, with the same leftover gas, on both runners.
+    for gas in [0, 1_000, 10_000, 123_456] {
+        let result_vm = run_vm_program(&program, "run_test", &[], Some(gas)).unwrap();
+        let result_native = run_native_program(
+            &program,
+            "run_test",
+            &[],
+            Some(gas as u128),
+            Option::<DummySyscallHandler>::None,
+        );
+
+        compare_outputs(
+            &program.1,
+            &program.2.find_function("run_test").unwrap().id,
+            &result_vm,
+            &result_native,
+        )
+        .unwrap();
+    }
+}
@@ -2,12 +2,16 @@ pub mod alexandria;
 pub mod arrays;
 pub mod boolean;
 pub mod cases;
+#[cfg(feature = "build-cli")]
+pub mod cli;
 pub mod compile_library;
 pub mod dict;
 pub mod ec;
 pub mod felt252;
 pub mod libfuncs;
 pub mod programs;
+pub mod recursion;
+pub mod reference;
 pub mod result;
 pub mod starknet;
 pub mod trampoline;
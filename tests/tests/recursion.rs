@@ -0,0 +1,117 @@
+//! Recursive programs, which rely on the OS stack when run natively.
+
+use crate::common::load_cairo;
+use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra::program::Program;
+use cairo_native::{
+    context::NativeContext, executor::JitNativeExecutor, utils::find_function_id, values::JitValue,
+    OptLevel,
+};
+
+/// Run the program's `run_test` function, on a thread with a stack of `stack_size` bytes if given.
+fn run_test(
+    program: &(String, Program, SierraCasmRunner),
+    stack_size: Option<usize>,
+    args: &[JitValue],
+) -> JitValue {
+    let entry_point = find_function_id(&program.1, &format!("{0}::{0}::run_test", program.0));
+
+    let context = NativeContext::new();
+    let module = context
+        .compile(&program.1, None)
+        .expect("Could not compile test program to MLIR.");
+
+    let mut executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+    if let Some(stack_size) = stack_size {
+        executor = executor.with_stack_size(stack_size);
+    }
+
+    executor
+        .invoke_dynamic(entry_point, args, Some(u128::MAX))
+        .unwrap()
+        .return_value
+}
+
+/// The `Ok` variant of a `PanicResult` returning `value`, which is what recursive functions return
+/// since they may run out of gas.
+fn panic_result_ok(value: JitValue) -> JitValue {
+    JitValue::Enum {
+        tag: 0,
+        value: Box::new(JitValue::Struct {
+            fields: vec![value],
+            debug_name: None,
+        }),
+        debug_name: None,
+    }
+}
+
+#[test]
+fn fib_30() {
+    let program = load_cairo! {
+        fn fib(n: u32) -> u32 {
+            if n < 2 {
+                n
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        }
+
+        fn run_test(n: u32) -> u32 {
+            fib(n)
+        }
+    };
+
+    assert_eq!(
+        run_test(&program, None, &[30u32.into()]),
+        panic_result_ok(JitValue::Uint32(832040))
+    );
+}
+
+#[test]
+fn tail_recursive_sum() {
+    let program = load_cairo! {
+        fn sum(n: felt252, acc: felt252) -> felt252 {
+            match n {
+                0 => acc,
+                _ => sum(n - 1, acc + n),
+            }
+        }
+
+        fn run_test(n: felt252) -> felt252 {
+            sum(n, 0)
+        }
+    };
+
+    // Direct tail-recursive calls are compiled into jumps back to the function's entry (see
+    // `TailRecursionMeta`) rather than `musttail` calls, so they don't grow the stack. A million
+    // calls only fit in a 64 KiB stack as long as that's the case.
+    assert_eq!(
+        run_test(&program, Some(64 * 1024), &[JitValue::felt_str("1000000")]),
+        panic_result_ok(JitValue::felt_str("500000500000"))
+    );
+}
+
+#[test]
+fn deep_recursion_with_stack_size() {
+    let program = load_cairo! {
+        fn sum(n: felt252) -> felt252 {
+            match n {
+                0 => 0,
+                _ => n + sum(n - 1),
+            }
+        }
+
+        fn run_test(n: felt252) -> felt252 {
+            sum(n)
+        }
+    };
+
+    assert_eq!(
+        run_test(
+            &program,
+            Some(1024 * 1024 * 1024),
+            &[JitValue::felt_str("100000")]
+        ),
+        panic_result_ok(JitValue::felt_str("5000050000"))
+    );
+}
@@ -0,0 +1,328 @@
+//! Differential tests comparing the native output of integer and felt252 libfuncs against a
+//! pure-Rust reference implementation.
+//!
+//! Every program is compiled once and then invoked with random operands. The number of cases per
+//! test may be overridden using the `PROPTEST_CASES` environment variable.
+
+use crate::common::load_cairo;
+use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra::program::Program;
+use cairo_native::{
+    context::NativeContext, executor::JitNativeExecutor, types::felt252::PRIME,
+    utils::find_function_id, values::JitValue, OptLevel,
+};
+use num_bigint::BigUint;
+use proptest::{
+    prelude::*,
+    test_runner::{Config, TestRunner},
+};
+use starknet_types_core::felt::Felt;
+use std::env;
+
+const DEFAULT_CASES: u32 = 64;
+
+fn config() -> Config {
+    Config::with_cases(
+        env::var("PROPTEST_CASES")
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(DEFAULT_CASES),
+    )
+}
+
+/// Compile the program's `run_test` function once and check it against `reference` for every
+/// value generated by `strategy`.
+fn check_against_reference<S>(
+    program: &(String, Program, SierraCasmRunner),
+    strategy: S,
+    args: impl Fn(&S::Value) -> Vec<JitValue>,
+    reference: impl Fn(&S::Value) -> JitValue,
+) where
+    S: Strategy,
+{
+    let entry_point = find_function_id(&program.1, &format!("{0}::{0}::run_test", program.0));
+
+    let context = NativeContext::new();
+    let module = context
+        .compile(&program.1, None)
+        .expect("Could not compile test program to MLIR.");
+    let executor = JitNativeExecutor::from_native_module(module, OptLevel::None);
+
+    let result = TestRunner::new(config()).run(&strategy, |value| {
+        let result = executor
+            .invoke_dynamic(entry_point, &args(&value), None)
+            .map_err(|e| TestCaseError::fail(e.to_string()))?;
+        prop_assert_eq!(result.return_value, reference(&value));
+        Ok(())
+    });
+
+    if let Err(e) = result {
+        panic!("{e}");
+    }
+}
+
+fn felt_strategy() -> impl Strategy<Value = BigUint> {
+    any::<[u8; 32]>().prop_map(|x| BigUint::from_bytes_be(&x) % &*PRIME)
+}
+
+fn to_felt(value: &BigUint) -> JitValue {
+    JitValue::Felt252(Felt::from_bytes_be_slice(&value.to_bytes_be()))
+}
+
+fn tuple(fields: Vec<JitValue>) -> JitValue {
+    JitValue::Struct {
+        fields,
+        debug_name: None,
+    }
+}
+
+fn variant(tag: usize, value: JitValue) -> JitValue {
+    JitValue::Enum {
+        tag,
+        value: Box::new(value),
+        debug_name: None,
+    }
+}
+
+fn overflowing(result: (impl Into<JitValue>, bool)) -> JitValue {
+    variant(result.1 as usize, result.0.into())
+}
+
+#[test]
+fn felt252_add() {
+    let program = load_cairo! {
+        fn run_test(lhs: felt252, rhs: felt252) -> felt252 {
+            lhs + rhs
+        }
+    };
+
+    check_against_reference(
+        &program,
+        (felt_strategy(), felt_strategy()),
+        |(a, b)| vec![to_felt(a), to_felt(b)],
+        |(a, b)| to_felt(&((a + b) % &*PRIME)),
+    );
+}
+
+#[test]
+fn felt252_sub() {
+    let program = load_cairo! {
+        fn run_test(lhs: felt252, rhs: felt252) -> felt252 {
+            lhs - rhs
+        }
+    };
+
+    check_against_reference(
+        &program,
+        (felt_strategy(), felt_strategy()),
+        |(a, b)| vec![to_felt(a), to_felt(b)],
+        |(a, b)| to_felt(&((a + &*PRIME - b) % &*PRIME)),
+    );
+}
+
+#[test]
+fn felt252_mul() {
+    let program = load_cairo! {
+        fn run_test(lhs: felt252, rhs: felt252) -> felt252 {
+            lhs * rhs
+        }
+    };
+
+    check_against_reference(
+        &program,
+        (felt_strategy(), felt_strategy()),
+        |(a, b)| vec![to_felt(a), to_felt(b)],
+        |(a, b)| to_felt(&((a * b) % &*PRIME)),
+    );
+}
+
+#[test]
+fn u8_overflowing_add() {
+    let program = load_cairo! {
+        extern fn u8_overflowing_add(lhs: u8, rhs: u8) -> Result<u8, u8> implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u8, rhs: u8) -> Result<u8, u8> {
+            u8_overflowing_add(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u8, u8)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| overflowing(a.overflowing_add(b)),
+    );
+}
+
+#[test]
+fn u16_overflowing_sub() {
+    let program = load_cairo! {
+        extern fn u16_overflowing_sub(lhs: u16, rhs: u16) -> Result<u16, u16> implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u16, rhs: u16) -> Result<u16, u16> {
+            u16_overflowing_sub(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u16, u16)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| overflowing(a.overflowing_sub(b)),
+    );
+}
+
+#[test]
+fn u32_overflowing_add() {
+    let program = load_cairo! {
+        extern fn u32_overflowing_add(lhs: u32, rhs: u32) -> Result<u32, u32> implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u32, rhs: u32) -> Result<u32, u32> {
+            u32_overflowing_add(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u32, u32)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| overflowing(a.overflowing_add(b)),
+    );
+}
+
+#[test]
+fn u64_overflowing_sub() {
+    let program = load_cairo! {
+        extern fn u64_overflowing_sub(lhs: u64, rhs: u64) -> Result<u64, u64> implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u64, rhs: u64) -> Result<u64, u64> {
+            u64_overflowing_sub(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u64, u64)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| overflowing(a.overflowing_sub(b)),
+    );
+}
+
+#[test]
+fn u128_overflowing_add() {
+    let program = load_cairo! {
+        extern fn u128_overflowing_add(lhs: u128, rhs: u128) -> Result<u128, u128> implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u128, rhs: u128) -> Result<u128, u128> {
+            u128_overflowing_add(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u128, u128)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| overflowing(a.overflowing_add(b)),
+    );
+}
+
+#[test]
+fn u128_overflowing_sub() {
+    let program = load_cairo! {
+        extern fn u128_overflowing_sub(lhs: u128, rhs: u128) -> Result<u128, u128> implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u128, rhs: u128) -> Result<u128, u128> {
+            u128_overflowing_sub(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u128, u128)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| overflowing(a.overflowing_sub(b)),
+    );
+}
+
+#[test]
+fn u128_safe_divmod() {
+    let program = load_cairo! {
+        use zeroable::IsZeroResult;
+
+        extern fn u128_is_zero(a: u128) -> IsZeroResult<u128> implicits() nopanic;
+        extern fn u128_safe_divmod(lhs: u128, rhs: NonZero<u128>) -> (u128, u128) implicits(RangeCheck) nopanic;
+
+        fn run_test(lhs: u128, rhs: u128) -> Option<(u128, u128)> nopanic {
+            match u128_is_zero(rhs) {
+                IsZeroResult::Zero(_) => Option::None,
+                IsZeroResult::NonZero(rhs) => Option::Some(u128_safe_divmod(lhs, rhs)),
+            }
+        }
+    };
+
+    check_against_reference(
+        &program,
+        (any::<u128>(), prop_oneof![Just(0), any::<u128>()]),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| match (a.checked_div(b), a.checked_rem(b)) {
+            (Some(q), Some(r)) => variant(0, tuple(vec![q.into(), r.into()])),
+            _ => variant(1, tuple(Vec::new())),
+        },
+    );
+}
+
+#[test]
+fn u64_wide_mul() {
+    let program = load_cairo! {
+        use integer::u64_wide_mul;
+
+        fn run_test(lhs: u64, rhs: u64) -> u128 {
+            u64_wide_mul(lhs, rhs)
+        }
+    };
+
+    check_against_reference(
+        &program,
+        any::<(u64, u64)>(),
+        |&(a, b)| vec![a.into(), b.into()],
+        |&(a, b)| (a as u128 * b as u128).into(),
+    );
+}
+
+#[test]
+fn downcast() {
+    let program = load_cairo! {
+        use core::integer::downcast;
+
+        fn run_test(value: u128) -> (Option<u64>, Option<u32>, Option<u16>, Option<u8>) {
+            (downcast(value), downcast(value), downcast(value), downcast(value))
+        }
+    };
+
+    fn option(value: Option<impl Into<JitValue>>) -> JitValue {
+        match value {
+            Some(x) => variant(0, x.into()),
+            None => variant(1, tuple(Vec::new())),
+        }
+    }
+
+    check_against_reference(
+        &program,
+        prop_oneof![
+            any::<u8>().prop_map(u128::from),
+            any::<u16>().prop_map(u128::from),
+            any::<u32>().prop_map(u128::from),
+            any::<u64>().prop_map(u128::from),
+            any::<u128>(),
+        ],
+        |&x| vec![x.into()],
+        |&x| {
+            tuple(vec![
+                option(u64::try_from(x).ok()),
+                option(u32::try_from(x).ok()),
+                option(u16::try_from(x).ok()),
+                option(u8::try_from(x).ok()),
+            ])
+        },
+    );
+}
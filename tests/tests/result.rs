@@ -90,6 +90,7 @@ fn test_cases(return_value: JitValue) {
         return_value,
         remaining_gas: None,
         builtin_stats: BuiltinStats::default(),
+        is_panic_result: false,
     })
     .unwrap();
 }
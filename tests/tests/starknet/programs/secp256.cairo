@@ -7,6 +7,8 @@ use core::starknet::{
         Secp256r1Point, secp256r1_new_syscall, secp256r1_add_syscall, secp256r1_mul_syscall,
         secp256r1_get_point_from_x_syscall, secp256r1_get_xy_syscall,
     },
+    secp256_trait::{recover_public_key, Secp256PointTrait, Signature},
+    SyscallResultTrait,
 };
 
 fn secp256k1_new(x: u256, y: u256) -> Result<Option<Secp256k1Point>, Array<felt252>> {
@@ -52,3 +54,15 @@ fn secp256r1_get_point_from_x(
 fn secp256r1_get_xy(p: Secp256r1Point) -> Result<(u256, u256), Array<felt252>> {
     secp256r1_get_xy_syscall(p)
 }
+
+fn secp256k1_recover_public_key() -> (u256, u256) {
+    let signature = Signature {
+        r: 0x4c8e4fbc1fbb1dece52185e532812c4f7a5f81cf3ee10044320a0d03b62d3e9a,
+        s: 0x4ac5e5c0c0e8a4871583cc131f35fb49c2b7f60e6a8b84965830658f08f7410c,
+        y_parity: true,
+    };
+    let msg_hash = 0xe888fbb4cf9ae6254f19ba12e6d9af54788f195a6f509ca3e934f78d7a71dd85;
+
+    let public_key = recover_public_key::<Secp256k1Point>(msg_hash, signature).unwrap();
+    public_key.get_coordinates().unwrap_syscall()
+}
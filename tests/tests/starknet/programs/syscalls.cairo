@@ -37,6 +37,18 @@ fn call_contract() -> SyscallResult<Span<felt252>> {
     call_contract_syscall(contract_address_const::<0>(), 0, array![].span())
 }
 
+fn deploy_echo() -> SyscallResult<(ContractAddress, Span<felt252>)> {
+    deploy_syscall(class_hash_const::<0>(), 0, array![1, 2, 3, 4, 5].span(), false)
+}
+
+fn library_call_echo() -> SyscallResult<Span<felt252>> {
+    library_call_syscall(class_hash_const::<0>(), 0, array![1, 2, 3, 4, 5].span())
+}
+
+fn call_contract_echo() -> SyscallResult<Span<felt252>> {
+    call_contract_syscall(contract_address_const::<0>(), 0, array![1, 2, 3, 4, 5].span())
+}
+
 fn storage_read() -> felt252 {
     storage_read_syscall(0, storage_address_try_from_felt252(0).unwrap()).unwrap()
 }
@@ -45,10 +57,28 @@ fn storage_write() {
     storage_write_syscall(0, storage_address_try_from_felt252(0).unwrap(), 0).unwrap()
 }
 
+fn storage_write_read() -> felt252 {
+    let address = storage_address_try_from_felt252(7).unwrap();
+    storage_write_syscall(0, address, 1234).unwrap();
+    storage_read_syscall(0, address).unwrap()
+}
+
+fn storage_read_revert() -> SyscallResult<felt252> {
+    storage_read_syscall(1, storage_address_try_from_felt252(0).unwrap())
+}
+
 fn emit_event() -> SyscallResult<()> {
     emit_event_syscall(array![].span(), array![].span())
 }
 
+fn emit_event_with_payload() -> SyscallResult<()> {
+    emit_event_syscall(array![1, 2].span(), array![3, 4, 5].span())
+}
+
+fn replace_class_undeclared() -> SyscallResult<()> {
+    replace_class_syscall(class_hash_const::<1>())
+}
+
 fn send_message_to_l1() -> SyscallResult<()> {
     send_message_to_l1_syscall(3, array![2].span())
 }
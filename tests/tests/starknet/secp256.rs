@@ -3,6 +3,7 @@ use cairo_lang_runner::SierraCasmRunner;
 use cairo_lang_sierra::program::Program;
 use cairo_native::{
     starknet::{Secp256k1Point, Secp256r1Point, StarknetSyscallHandler, SyscallResult, U256},
+    starknet_stub::StubSyscallHandler,
     values::JitValue,
 };
 use lazy_static::lazy_static;
@@ -2085,3 +2086,44 @@ fn secp256r1_get_xy() {
     );
     assert!(syscall_handler.secp256r1_get_xy.1.is_empty());
 }
+
+#[test]
+fn secp256k1_recover_public_key() {
+    let result = run_native_program(
+        &SECP256_PROGRAM,
+        "secp256k1_recover_public_key",
+        &[],
+        Some(u128::MAX),
+        Some(&mut StubSyscallHandler::default()),
+    );
+
+    assert_eq!(
+        result.return_value,
+        JitValue::Enum {
+            tag: 0,
+            value: Box::new(JitValue::Struct {
+                fields: vec![JitValue::Struct {
+                    fields: vec![
+                        JitValue::Struct {
+                            fields: vec![
+                                JitValue::Uint128(338548311424903992834842166374951939837),
+                                JitValue::Uint128(225471217200374563373199177152572765703),
+                            ],
+                            debug_name: None,
+                        },
+                        JitValue::Struct {
+                            fields: vec![
+                                JitValue::Uint128(276990627032739421360488693585787994270),
+                                JitValue::Uint128(32836424217673307285671869177989210021),
+                            ],
+                            debug_name: None,
+                        },
+                    ],
+                    debug_name: None,
+                }],
+                debug_name: None,
+            }),
+            debug_name: None,
+        },
+    );
+}
@@ -8,7 +8,7 @@ use cairo_lang_runner::SierraCasmRunner;
 use cairo_lang_sierra::program::Program;
 use cairo_native::{
     starknet::{
-        BlockInfo, ExecutionInfo, ExecutionInfoV2, Secp256k1Point, Secp256r1Point,
+        BlockInfo, ExecutionInfo, ExecutionInfoV2, ResourceBounds, Secp256k1Point, Secp256r1Point,
         StarknetSyscallHandler, SyscallResult, TxInfo, TxV2Info, U256,
     },
     values::JitValue,
@@ -42,6 +42,7 @@ struct TestingState {
     block_timestamp: u64,
     signature: Vec<Felt>,
     logs: HashMap<Felt, ContractLogs>,
+    storage: HashMap<Felt, Felt>,
 }
 
 struct SyscallHandler {
@@ -160,7 +161,18 @@ impl StarknetSyscallHandler for SyscallHandler {
                     "3012936192361023209451741736298028332652992971202997279327088951248532774884",
                 )
                 .unwrap(),
-                resource_bounds: Vec::new(),
+                resource_bounds: vec![
+                    ResourceBounds {
+                        resource: Felt::from_dec_str("83774935613779").unwrap(),
+                        max_amount: 1000,
+                        max_price_per_unit: 2000,
+                    },
+                    ResourceBounds {
+                        resource: Felt::from_dec_str("83779230581075").unwrap(),
+                        max_amount: 3000,
+                        max_price_per_unit: 4000,
+                    },
+                ],
                 tip: 215444579144685671333997376989135077200,
                 paymaster_data: Vec::new(),
                 nonce_data_availability_mode: 140600095,
@@ -186,7 +198,7 @@ impl StarknetSyscallHandler for SyscallHandler {
         &mut self,
         _class_hash: Felt,
         _contract_address_salt: Felt,
-        _calldata: &[Felt],
+        calldata: &[Felt],
         _deploy_from_zero: bool,
         _remaining_gas: &mut u128,
     ) -> SyscallResult<(Felt, Vec<Felt>)> {
@@ -195,11 +207,19 @@ impl StarknetSyscallHandler for SyscallHandler {
                 "1833707083418045616336697070784512826809940908236872124572250196391719980392",
             )
             .unwrap(),
-            Vec::new(),
+            calldata.to_vec(),
         ))
     }
 
-    fn replace_class(&mut self, _class_hash: Felt, _remaining_gas: &mut u128) -> SyscallResult<()> {
+    fn replace_class(&mut self, class_hash: Felt, _remaining_gas: &mut u128) -> SyscallResult<()> {
+        // Only the zero class hash is considered declared.
+        if class_hash != Felt::ZERO {
+            return Err(vec![Felt::from_dec_str(
+                "5873403187620174331433331599423500939584868",
+            )
+            .unwrap()]);
+        }
+
         Ok(())
     }
 
@@ -207,9 +227,13 @@ impl StarknetSyscallHandler for SyscallHandler {
         &mut self,
         _class_hash: Felt,
         _function_selector: Felt,
-        _calldata: &[Felt],
+        calldata: &[Felt],
         _remaining_gas: &mut u128,
     ) -> SyscallResult<Vec<Felt>> {
+        if !calldata.is_empty() {
+            return Ok(calldata.to_vec());
+        }
+
         Ok(vec![
             Felt::from_dec_str(
                 "3358892263739032253767642605669710712087178958719188919195252597609334880396",
@@ -230,9 +254,13 @@ impl StarknetSyscallHandler for SyscallHandler {
         &mut self,
         _address: Felt,
         _entry_point_selector: Felt,
-        _calldata: &[Felt],
+        calldata: &[Felt],
         _remaining_gas: &mut u128,
     ) -> SyscallResult<Vec<Felt>> {
+        if !calldata.is_empty() {
+            return Ok(calldata.to_vec());
+        }
+
         Ok(vec![
             Felt::from_dec_str(
                 "3358892263739032253767642605669710712087178958719188919195252597609334880396",
@@ -251,32 +279,61 @@ impl StarknetSyscallHandler for SyscallHandler {
 
     fn storage_read(
         &mut self,
-        _address_domain: u32,
-        _address: Felt,
+        address_domain: u32,
+        address: Felt,
         _remaining_gas: &mut u128,
     ) -> SyscallResult<Felt> {
-        Ok(Felt::from_dec_str(
-            "1013181629378419652272218169322268188846114273878719855200100663863924329981",
-        )
-        .unwrap())
+        if address_domain != 0 {
+            return Err(vec![Felt::from_dec_str(
+                "137283045993144043501733121243818978359763286750835609782282606",
+            )
+            .unwrap()]);
+        }
+
+        Ok(self
+            .testing_state
+            .lock()
+            .unwrap()
+            .storage
+            .get(&address)
+            .copied()
+            .unwrap_or_else(|| {
+                Felt::from_dec_str(
+                    "1013181629378419652272218169322268188846114273878719855200100663863924329981",
+                )
+                .unwrap()
+            }))
     }
 
     fn storage_write(
         &mut self,
         _address_domain: u32,
-        _address: Felt,
-        _value: Felt,
+        address: Felt,
+        value: Felt,
         _remaining_gas: &mut u128,
     ) -> SyscallResult<()> {
+        self.testing_state
+            .lock()
+            .unwrap()
+            .storage
+            .insert(address, value);
         Ok(())
     }
 
     fn emit_event(
         &mut self,
-        _keys: &[Felt],
-        _data: &[Felt],
+        keys: &[Felt],
+        data: &[Felt],
         _remaining_gas: &mut u128,
     ) -> SyscallResult<()> {
+        let mut state = self.testing_state.lock().unwrap();
+        let contract_address = state.contract_address;
+        state
+            .logs
+            .entry(contract_address)
+            .or_default()
+            .events
+            .push_back((keys.to_vec(), data.to_vec()));
         Ok(())
     }
 
@@ -660,7 +717,28 @@ fn get_execution_info_v2() {
                             )
                             .unwrap()),
                             JitValue::Struct {
-                                fields: vec![JitValue::Array(Vec::new())],
+                                fields: vec![JitValue::Array(vec![
+                                    JitValue::Struct {
+                                        fields: vec![
+                                            JitValue::Felt252(
+                                                Felt::from_dec_str("83774935613779").unwrap()
+                                            ),
+                                            JitValue::Uint64(1000),
+                                            JitValue::Uint128(2000),
+                                        ],
+                                        debug_name: None,
+                                    },
+                                    JitValue::Struct {
+                                        fields: vec![
+                                            JitValue::Felt252(
+                                                Felt::from_dec_str("83779230581075").unwrap()
+                                            ),
+                                            JitValue::Uint64(3000),
+                                            JitValue::Uint128(4000),
+                                        ],
+                                        debug_name: None,
+                                    },
+                                ])],
                                 debug_name: None,
                             },
                             JitValue::Uint128(215444579144685671333997376989135077200),
@@ -824,6 +902,63 @@ fn call_contract() {
     );
 }
 
+#[test]
+fn invocation_syscalls_echo_calldata() {
+    let calldata = || JitValue::Struct {
+        fields: vec![JitValue::Array(
+            (1..=5).map(|x| JitValue::Felt252(Felt::from(x))).collect(),
+        )],
+        debug_name: None,
+    };
+
+    for entry_point in ["library_call_echo", "call_contract_echo"] {
+        let result = run_native_program(
+            &SYSCALLS_PROGRAM,
+            entry_point,
+            &[],
+            Some(u128::MAX),
+            Some(SyscallHandler::new()),
+        );
+
+        assert_eq_sorted!(
+            result.return_value,
+            JitValue::Enum {
+                tag: 0,
+                value: Box::new(calldata()),
+                debug_name: None,
+            },
+        );
+    }
+
+    let result = run_native_program(
+        &SYSCALLS_PROGRAM,
+        "deploy_echo",
+        &[],
+        Some(u128::MAX),
+        Some(SyscallHandler::new()),
+    );
+
+    assert_eq_sorted!(
+        result.return_value,
+        JitValue::Enum {
+            tag: 0,
+            value: Box::new(JitValue::Struct {
+                fields: vec![
+                    JitValue::Felt252(
+                        Felt::from_dec_str(
+                            "1833707083418045616336697070784512826809940908236872124572250196391719980392",
+                        )
+                        .unwrap()
+                    ),
+                    calldata(),
+                ],
+                debug_name: None,
+            }),
+            debug_name: None,
+        },
+    );
+}
+
 #[test]
 fn storage_read() {
     let result = run_native_program(
@@ -878,6 +1013,61 @@ fn storage_write() {
     );
 }
 
+#[test]
+fn storage_write_read() {
+    let result = run_native_program(
+        &SYSCALLS_PROGRAM,
+        "storage_write_read",
+        &[],
+        Some(u128::MAX),
+        Some(SyscallHandler::new()),
+    );
+
+    assert_eq_sorted!(
+        result.return_value,
+        JitValue::Enum {
+            tag: 0,
+            value: Box::new(JitValue::Struct {
+                fields: vec![JitValue::Felt252(Felt::from(1234))],
+                debug_name: None,
+            }),
+            debug_name: None,
+        },
+    );
+}
+
+#[test]
+fn storage_read_revert() {
+    let result = run_native_program(
+        &SYSCALLS_PROGRAM,
+        "storage_read_revert",
+        &[],
+        Some(u128::MAX),
+        Some(SyscallHandler::new()),
+    );
+
+    assert_eq_sorted!(
+        result.return_value,
+        JitValue::Enum {
+            tag: 0,
+            value: Box::new(JitValue::Struct {
+                fields: vec![JitValue::Enum {
+                    tag: 1,
+                    value: Box::new(JitValue::Array(vec![JitValue::Felt252(
+                        Felt::from_dec_str(
+                            "137283045993144043501733121243818978359763286750835609782282606",
+                        )
+                        .unwrap()
+                    )])),
+                    debug_name: None,
+                }],
+                debug_name: None,
+            }),
+            debug_name: None,
+        },
+    );
+}
+
 #[test]
 fn emit_event() {
     let result = run_native_program(
@@ -901,6 +1091,63 @@ fn emit_event() {
     );
 }
 
+#[test]
+fn emit_event_recorded() {
+    let state = Arc::new(Mutex::new(TestingState::default()));
+
+    let result = run_native_program(
+        &SYSCALLS_PROGRAM,
+        "emit_event_with_payload",
+        &[],
+        Some(u128::MAX),
+        Some(SyscallHandler::with(state.clone())),
+    );
+
+    assert_eq_sorted!(
+        result.return_value,
+        JitValue::Enum {
+            tag: 0,
+            value: Box::new(JitValue::Struct {
+                fields: Vec::new(),
+                debug_name: None,
+            }),
+            debug_name: None,
+        },
+    );
+
+    let state = state.lock().unwrap();
+    let events = &state.logs.get(&state.contract_address).unwrap().events;
+    assert_eq!(
+        events,
+        &VecDeque::from(vec![(
+            vec![Felt::ONE, Felt::TWO],
+            vec![Felt::THREE, Felt::from(4), Felt::from(5)],
+        )]),
+    );
+}
+
+#[test]
+fn replace_class_revert() {
+    let result = run_native_program(
+        &SYSCALLS_PROGRAM,
+        "replace_class_undeclared",
+        &[],
+        Some(u128::MAX),
+        Some(SyscallHandler::new()),
+    );
+
+    assert_eq_sorted!(
+        result.return_value,
+        JitValue::Enum {
+            tag: 1,
+            value: Box::new(JitValue::Array(vec![JitValue::Felt252(
+                Felt::from_dec_str("5873403187620174331433331599423500939584868").unwrap()
+            )])),
+            debug_name: None,
+        },
+    );
+}
+
 #[test]
 fn send_message_to_l1() {
     let result = run_native_program(
@@ -36,6 +36,7 @@ fn invoke0() {
                 debug_name: None,
             },
             builtin_stats: BuiltinStats::default(),
+            is_panic_result: false,
         },
     );
 }
@@ -60,6 +61,7 @@ fn invoke1_felt252() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -90,6 +92,7 @@ fn invoke1_u8() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -120,6 +123,7 @@ fn invoke1_u16() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -150,6 +154,7 @@ fn invoke1_u32() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -180,6 +185,7 @@ fn invoke1_u64() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -210,6 +216,7 @@ fn invoke1_u128() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -243,6 +250,7 @@ fn invoke1_tuple1_felt252() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -276,6 +284,7 @@ fn invoke1_tuple1_u64() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -315,6 +324,7 @@ fn invoke1_tuple5_u8_u16_u32_u64_u128() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -345,6 +355,7 @@ fn invoke1_array_felt252() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -386,6 +397,7 @@ fn invoke1_enum1_unit() {
             remaining_gas: None,
             return_value: x,
             builtin_stats: BuiltinStats::default(),
+            is_panic_result: false,
         },
     );
 }
@@ -418,6 +430,7 @@ fn invoke1_enum1_u64() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -456,6 +469,7 @@ fn invoke1_enum1_felt252() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
@@ -507,6 +521,7 @@ fn invoke1_enum2_u8_u16() {
                 remaining_gas: None,
                 return_value: x,
                 builtin_stats: BuiltinStats::default(),
+                is_panic_result: false,
             },
         );
     };
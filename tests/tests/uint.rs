@@ -566,6 +566,32 @@ proptest! {
         )?;
     }
 
+    #[test]
+    fn u8_sqrt_proptest(a in 0..u8::MAX) {
+        let program = &U8_SQRT;
+        let result_vm = run_vm_program(
+            program,
+            "run_test",
+            &[Arg::Value(a.into())],
+            Some(DEFAULT_GAS as usize),
+        )
+        .unwrap();
+        let result_native = run_native_program(
+            program,
+            "run_test",
+            &[JitValue::Felt252(a.into())],
+            Some(DEFAULT_GAS as u128),
+            Option::<DummySyscallHandler>::None,
+        );
+
+        compare_outputs(
+            &program.1,
+            &program.2.find_function("run_test").unwrap().id,
+            &result_vm,
+            &result_native,
+        )?;
+    }
+
     // u16
 
     #[test]
@@ -698,6 +724,32 @@ proptest! {
         )?;
     }
 
+    #[test]
+    fn u16_sqrt_proptest(a in 0..u16::MAX) {
+        let program = &U16_SQRT;
+        let result_vm = run_vm_program(
+            program,
+            "run_test",
+            &[Arg::Value(a.into())],
+            Some(DEFAULT_GAS as usize),
+        )
+        .unwrap();
+        let result_native = run_native_program(
+            program,
+            "run_test",
+            &[JitValue::Felt252(a.into())],
+            Some(DEFAULT_GAS as u128),
+            Option::<DummySyscallHandler>::None,
+        );
+
+        compare_outputs(
+            &program.1,
+            &program.2.find_function("run_test").unwrap().id,
+            &result_vm,
+            &result_native,
+        )?;
+    }
+
     // u32
 
     #[test]
@@ -830,6 +882,32 @@ proptest! {
         )?;
     }
 
+    #[test]
+    fn u32_sqrt_proptest(a in 0..u32::MAX) {
+        let program = &U32_SQRT;
+        let result_vm = run_vm_program(
+            program,
+            "run_test",
+            &[Arg::Value(a.into())],
+            Some(DEFAULT_GAS as usize),
+        )
+        .unwrap();
+        let result_native = run_native_program(
+            program,
+            "run_test",
+            &[JitValue::Felt252(a.into())],
+            Some(DEFAULT_GAS as u128),
+            Option::<DummySyscallHandler>::None,
+        );
+
+        compare_outputs(
+            &program.1,
+            &program.2.find_function("run_test").unwrap().id,
+            &result_vm,
+            &result_native,
+        )?;
+    }
+
     // u64
 
     #[test]
@@ -962,6 +1040,32 @@ proptest! {
         )?;
     }
 
+    #[test]
+    fn u64_sqrt_proptest(a in 0..u64::MAX) {
+        let program = &U64_SQRT;
+        let result_vm = run_vm_program(
+            program,
+            "run_test",
+            &[Arg::Value(a.into())],
+            Some(DEFAULT_GAS as usize),
+        )
+        .unwrap();
+        let result_native = run_native_program(
+            program,
+            "run_test",
+            &[JitValue::Felt252(a.into())],
+            Some(DEFAULT_GAS as u128),
+            Option::<DummySyscallHandler>::None,
+        );
+
+        compare_outputs(
+            &program.1,
+            &program.2.find_function("run_test").unwrap().id,
+            &result_vm,
+            &result_native,
+        )?;
+    }
+
     // u128
 
     #[test]
@@ -1093,4 +1197,30 @@ proptest! {
             &result_native,
         )?;
     }
+
+    #[test]
+    fn u128_sqrt_proptest(a in 0..u128::MAX) {
+        let program = &U128_SQRT;
+        let result_vm = run_vm_program(
+            program,
+            "run_test",
+            &[Arg::Value(a.into())],
+            Some(DEFAULT_GAS as usize),
+        )
+        .unwrap();
+        let result_native = run_native_program(
+            program,
+            "run_test",
+            &[JitValue::Felt252(a.into())],
+            Some(DEFAULT_GAS as u128),
+            Option::<DummySyscallHandler>::None,
+        );
+
+        compare_outputs(
+            &program.1,
+            &program.2.find_function("run_test").unwrap().id,
+            &result_vm,
+            &result_native,
+        )?;
+    }
 }